@@ -132,6 +132,7 @@ fn generate_new_fn(builder: &OperationBuilder) -> TokenStream {
 
     quote! {
         impl<'c> #identifier<'c, #(#arguments),*> {
+            #[track_caller]
             pub fn new(context: &'c ::melior::Context, location: ::melior::ir::Location<'c>) -> Self {
                 Self {
                     context,
@@ -149,6 +150,7 @@ pub fn generate_operation_builder_fn(builder: &OperationBuilder) -> TokenStream
 
     quote! {
         /// Creates a builder.
+        #[track_caller]
         pub fn builder(
             context: &'c ::melior::Context,
             location: ::melior::ir::Location<'c>
@@ -187,6 +189,7 @@ pub fn generate_default_constructor(builder: &OperationBuilder) -> TokenStream {
     quote! {
         #[allow(clippy::too_many_arguments)]
         #[doc = #doc]
+        #[track_caller]
         pub fn #constructor_identifier<'c>(context: &'c ::melior::Context, #(#arguments),*) -> #operation_identifier<'c> {
             #operation_identifier::builder(context, location)#(#builder_calls)*.build()
         }
@@ -12,6 +12,7 @@ pub fn generate_binary(dialect: &Ident, names: &[Ident]) -> Result<TokenStream,
 
         stream.extend(TokenStream::from(quote! {
             #[doc = #document]
+            #[cfg_attr(feature = "track-caller", track_caller)]
             pub fn #name<'c>(
                 lhs: crate::ir::Value<'c, '_>,
                 rhs: crate::ir::Value<'c, '_>,
@@ -23,6 +24,7 @@ pub fn generate_binary(dialect: &Ident, names: &[Ident]) -> Result<TokenStream,
     }
 
     stream.extend(TokenStream::from(quote! {
+        #[cfg_attr(feature = "track-caller", track_caller)]
         fn binary_operator<'c>(
             name: &str,
             lhs: crate::ir::Value<'c, '_>,
@@ -49,6 +51,7 @@ pub fn generate_unary(dialect: &Ident, names: &[Ident]) -> Result<TokenStream, B
 
         stream.extend(TokenStream::from(quote! {
             #[doc = #document]
+            #[cfg_attr(feature = "track-caller", track_caller)]
             pub fn #name<'c>(
                 value: crate::ir::Value<'c, '_>,
                 location: crate::ir::Location<'c>,
@@ -59,6 +62,7 @@ pub fn generate_unary(dialect: &Ident, names: &[Ident]) -> Result<TokenStream, B
     }
 
     stream.extend(TokenStream::from(quote! {
+        #[cfg_attr(feature = "track-caller", track_caller)]
         fn unary_operator<'c>(
             name: &str,
             value: crate::ir::Value<'c, '_>,
@@ -87,6 +91,7 @@ pub fn generate_typed_unary(
 
         stream.extend(TokenStream::from(quote! {
             #[doc = #document]
+            #[cfg_attr(feature = "track-caller", track_caller)]
             pub fn #name<'c>(
                 value: crate::ir::Value<'c, '_>,
                 r#type: crate::ir::Type<'c>,
@@ -98,6 +103,7 @@ pub fn generate_typed_unary(
     }
 
     stream.extend(TokenStream::from(quote! {
+        #[cfg_attr(feature = "track-caller", track_caller)]
         fn typed_unary_operator<'c>(
             name: &str,
             value: crate::ir::Value<'c, '_>,
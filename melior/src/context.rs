@@ -1,5 +1,9 @@
+mod options;
+mod thread_pool;
+
+pub use self::{options::ContextOptions, thread_pool::ThreadPool};
 use crate::{
-    diagnostic::{Diagnostic, DiagnosticHandlerId},
+    diagnostic::{Diagnostic, DiagnosticCapture, DiagnosticHandlerId},
     dialect::{Dialect, DialectRegistry},
     logical_result::LogicalResult,
     string_ref::StringRef,
@@ -8,7 +12,7 @@ use mlir_sys::{
     mlirContextAppendDialectRegistry, mlirContextAttachDiagnosticHandler, mlirContextCreate,
     mlirContextDestroy, mlirContextDetachDiagnosticHandler, mlirContextEnableMultithreading,
     mlirContextEqual, mlirContextGetAllowUnregisteredDialects, mlirContextGetNumLoadedDialects,
-    mlirContextGetNumRegisteredDialects, mlirContextGetOrLoadDialect,
+    mlirContextGetNumRegisteredDialects, mlirContextGetNumThreads, mlirContextGetOrLoadDialect,
     mlirContextIsRegisteredOperation, mlirContextLoadAllAvailableDialects,
     mlirContextSetAllowUnregisteredDialects, MlirContext, MlirDiagnostic, MlirLogicalResult,
 };
@@ -31,6 +35,15 @@ impl Context {
         }
     }
 
+    /// Creates a context from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirContext) -> Self {
+        Self { raw }
+    }
+
     /// Returns a number of registered dialects.
     pub fn registered_dialect_count(&self) -> usize {
         unsafe { mlirContextGetNumRegisteredDialects(self.raw) as usize }
@@ -63,6 +76,11 @@ impl Context {
         unsafe { mlirContextEnableMultithreading(self.raw, enabled) }
     }
 
+    /// Returns a number of threads used by a context.
+    pub fn thread_count(&self) -> usize {
+        unsafe { mlirContextGetNumThreads(self.raw) as usize }
+    }
+
     /// Returns `true` if unregistered dialects are allowed.
     pub fn allow_unregistered_dialects(&self) -> bool {
         unsafe { mlirContextGetAllowUnregisteredDialects(self.raw) }
@@ -111,6 +129,11 @@ impl Context {
         }
     }
 
+    /// Captures diagnostics while a returned capture is alive.
+    pub fn capture_diagnostics(&self) -> DiagnosticCapture {
+        DiagnosticCapture::new(self)
+    }
+
     /// Detaches a diagnostic handler.
     pub fn detach_diagnostic_handler(&self, id: DiagnosticHandlerId) {
         unsafe { mlirContextDetachDiagnosticHandler(self.to_raw(), id.to_raw()) }
@@ -256,6 +279,15 @@ mod tests {
         context.enable_multi_threading(false);
     }
 
+    #[test]
+    fn thread_count() {
+        let context = Context::new();
+
+        context.enable_multi_threading(false);
+
+        assert_eq!(context.thread_count(), 1);
+    }
+
     #[test]
     fn allow_unregistered_dialects() {
         let context = Context::new();
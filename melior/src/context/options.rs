@@ -0,0 +1,148 @@
+use super::{Context, ThreadPool};
+use crate::dialect::DialectRegistry;
+use mlir_sys::{
+    mlirContextCreateWithRegistry, mlirContextCreateWithThreading, mlirContextSetThreadPool,
+};
+
+/// Options of a context.
+#[derive(Clone, Copy, Debug)]
+pub struct ContextOptions<'a> {
+    threading: bool,
+    allow_unregistered_dialects: bool,
+    registry: Option<&'a DialectRegistry>,
+    thread_pool: Option<&'static ThreadPool>,
+}
+
+impl<'a> ContextOptions<'a> {
+    /// Creates context options.
+    ///
+    /// Multi-threading is enabled by default as in [`Context::new`].
+    pub const fn new() -> Self {
+        Self {
+            threading: true,
+            allow_unregistered_dialects: false,
+            registry: None,
+            thread_pool: None,
+        }
+    }
+
+    /// Enables multi-threading.
+    pub const fn threading(mut self, enabled: bool) -> Self {
+        self.threading = enabled;
+        self
+    }
+
+    /// Sets if unregistered dialects are allowed.
+    pub const fn allow_unregistered_dialects(mut self, allowed: bool) -> Self {
+        self.allow_unregistered_dialects = allowed;
+        self
+    }
+
+    /// Sets a dialect registry appended to a context.
+    pub const fn dialect_registry(mut self, registry: &'a DialectRegistry) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Sets a thread pool shared with other contexts.
+    ///
+    /// Otherwise, each context creates its own thread pool when multi-threading
+    /// is enabled. A thread pool must outlive every context using it. So it
+    /// needs to be static, such as [`ThreadPool::global`] or a leaked one. It
+    /// is ignored if multi-threading is disabled.
+    pub const fn thread_pool(mut self, thread_pool: &'static ThreadPool) -> Self {
+        self.thread_pool = Some(thread_pool);
+        self
+    }
+
+    /// Builds a context.
+    pub fn build(&self) -> Context {
+        // A context needs to be created without its own thread pool before a shared
+        // one is set.
+        let threading = self.threading && self.thread_pool.is_none();
+
+        let context = unsafe {
+            Context::from_raw(if let Some(registry) = self.registry {
+                mlirContextCreateWithRegistry(registry.to_raw(), threading)
+            } else {
+                mlirContextCreateWithThreading(threading)
+            })
+        };
+
+        if let Some(thread_pool) = self.thread_pool.filter(|_| self.threading) {
+            unsafe { mlirContextSetThreadPool(context.to_raw(), thread_pool.to_raw()) }
+        }
+
+        context.set_allow_unregistered_dialects(self.allow_unregistered_dialects);
+
+        context
+    }
+}
+
+impl Default for ContextOptions<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utility::register_all_dialects;
+
+    #[test]
+    fn build() {
+        let context = ContextOptions::new().build();
+
+        assert_eq!(context.registered_dialect_count(), 1);
+        assert!(!context.allow_unregistered_dialects());
+    }
+
+    #[test]
+    fn build_without_threading() {
+        let context = ContextOptions::new().threading(false).build();
+
+        assert_eq!(context.thread_count(), 1);
+    }
+
+    #[test]
+    fn build_with_unregistered_dialects() {
+        assert!(ContextOptions::new()
+            .allow_unregistered_dialects(true)
+            .build()
+            .allow_unregistered_dialects());
+    }
+
+    #[test]
+    fn build_with_dialect_registry() {
+        let registry = DialectRegistry::new();
+        register_all_dialects(&registry);
+
+        let context = ContextOptions::new().dialect_registry(&registry).build();
+
+        assert!(context.registered_dialect_count() > 1);
+    }
+
+    #[test]
+    fn build_with_thread_pool() {
+        let one = ContextOptions::new()
+            .thread_pool(ThreadPool::global())
+            .build();
+        let other = ContextOptions::new()
+            .thread_pool(ThreadPool::global())
+            .build();
+
+        assert!(one.thread_count() >= 1);
+        assert_eq!(one.thread_count(), other.thread_count());
+    }
+
+    #[test]
+    fn build_with_thread_pool_without_threading() {
+        let context = ContextOptions::new()
+            .threading(false)
+            .thread_pool(ThreadPool::global())
+            .build();
+
+        assert_eq!(context.thread_count(), 1);
+    }
+}
@@ -0,0 +1,76 @@
+use mlir_sys::{mlirLlvmThreadPoolCreate, mlirLlvmThreadPoolDestroy, MlirLlvmThreadPool};
+use std::sync::OnceLock;
+
+/// An LLVM thread pool.
+///
+/// It can be shared by multiple contexts so that they do not spawn their own
+/// threads. See [`ContextOptions::thread_pool`](super::ContextOptions::thread_pool).
+#[derive(Debug)]
+pub struct ThreadPool {
+    raw: MlirLlvmThreadPool,
+}
+
+impl ThreadPool {
+    /// Creates a thread pool with as many threads as the hardware supports.
+    pub fn new() -> Self {
+        unsafe { Self::from_raw(mlirLlvmThreadPoolCreate()) }
+    }
+
+    /// Returns a global thread pool shared in a process.
+    ///
+    /// It is created on the first call and never destroyed.
+    pub fn global() -> &'static Self {
+        static POOL: OnceLock<ThreadPool> = OnceLock::new();
+
+        POOL.get_or_init(Self::new)
+    }
+
+    /// Creates a thread pool from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirLlvmThreadPool) -> Self {
+        Self { raw }
+    }
+
+    /// Converts a thread pool into a raw object.
+    pub const fn to_raw(&self) -> MlirLlvmThreadPool {
+        self.raw
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        unsafe { mlirLlvmThreadPoolDestroy(self.raw) }
+    }
+}
+
+impl Default for ThreadPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// LLVM thread pools are thread-safe as multiple contexts on different threads
+// enqueue tasks into them.
+unsafe impl Send for ThreadPool {}
+unsafe impl Sync for ThreadPool {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        ThreadPool::new();
+    }
+
+    #[test]
+    fn global() {
+        assert_eq!(
+            ThreadPool::global().to_raw().ptr,
+            ThreadPool::global().to_raw().ptr
+        );
+    }
+}
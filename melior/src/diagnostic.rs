@@ -1,9 +1,20 @@
 //! Diagnostics.
 
+mod capture;
 mod handler_id;
+mod owned;
+mod renderer;
 mod severity;
-
-pub use self::{handler_id::DiagnosticHandlerId, severity::DiagnosticSeverity};
+mod source;
+
+pub use self::{
+    capture::DiagnosticCapture,
+    handler_id::DiagnosticHandlerId,
+    owned::OwnedDiagnostic,
+    renderer::DiagnosticRenderer,
+    severity::DiagnosticSeverity,
+    source::{FileSystemSourceProvider, SourceProvider},
+};
 use crate::{ir::Location, utility::print_callback, Error};
 use mlir_sys::{
     mlirDiagnosticGetLocation, mlirDiagnosticGetNote, mlirDiagnosticGetNumNotes,
@@ -78,7 +89,11 @@ impl Display for Diagnostic<'_> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{ir::Module, Context};
+    use super::*;
+    use crate::{
+        ir::{Module, OwnedLocation},
+        Context,
+    };
 
     #[test]
     fn handle_diagnostic() {
@@ -97,4 +112,71 @@ mod tests {
             "custom op 'foo' is unknown (tried 'builtin.foo' as well)"
         );
     }
+
+    #[test]
+    fn capture() {
+        let context = Context::new();
+        let capture = DiagnosticCapture::new(&context);
+
+        Module::parse(&context, "foo");
+
+        let diagnostics = capture.finish();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity(), DiagnosticSeverity::Error);
+        assert_eq!(
+            diagnostics[0].message(),
+            "custom op 'foo' is unknown (tried 'builtin.foo' as well)"
+        );
+        assert!(diagnostics[0].notes().is_empty());
+    }
+
+    #[test]
+    fn capture_take() {
+        let context = Context::new();
+        let capture = context.capture_diagnostics();
+
+        Module::parse(&context, "foo");
+
+        assert_eq!(capture.diagnostics().len(), 1);
+        assert_eq!(capture.take().len(), 1);
+        assert_eq!(capture.take(), []);
+    }
+
+    #[test]
+    fn capture_nested() {
+        let context = Context::new();
+        let outer = context.capture_diagnostics();
+
+        {
+            let inner = context.capture_diagnostics();
+
+            Module::parse(&context, "foo");
+
+            assert_eq!(inner.finish().len(), 1);
+        }
+
+        Module::parse(&context, "bar");
+
+        assert_eq!(outer.finish().len(), 1);
+    }
+
+    #[test]
+    fn display_owned_diagnostic() {
+        assert_eq!(
+            OwnedDiagnostic::new(
+                DiagnosticSeverity::Error,
+                OwnedLocation::Unknown,
+                "foo",
+                vec![OwnedDiagnostic::new(
+                    DiagnosticSeverity::Note,
+                    OwnedLocation::Unknown,
+                    "bar",
+                    vec![]
+                )]
+            )
+            .to_string(),
+            "loc(unknown): error: foo\nloc(unknown): note: bar"
+        );
+    }
 }
@@ -0,0 +1,61 @@
+use super::{DiagnosticHandlerId, OwnedDiagnostic};
+use crate::Context;
+use std::{
+    mem::take,
+    sync::{Arc, Mutex},
+};
+
+/// A diagnostic capture.
+///
+/// It collects diagnostics emitted in a context while it is alive. Captured
+/// diagnostics are not propagated to the other diagnostic handlers.
+pub struct DiagnosticCapture<'c> {
+    context: &'c Context,
+    id: DiagnosticHandlerId,
+    diagnostics: Arc<Mutex<Vec<OwnedDiagnostic>>>,
+}
+
+impl<'c> DiagnosticCapture<'c> {
+    /// Starts capturing diagnostics in a context.
+    pub fn new(context: &'c Context) -> Self {
+        let diagnostics = Arc::<Mutex<Vec<_>>>::default();
+        let id = context.attach_diagnostic_handler({
+            let diagnostics = diagnostics.clone();
+
+            move |diagnostic| {
+                diagnostics
+                    .lock()
+                    .expect("diagnostics lock")
+                    .push(OwnedDiagnostic::from(&diagnostic));
+                true
+            }
+        });
+
+        Self {
+            context,
+            id,
+            diagnostics,
+        }
+    }
+
+    /// Returns diagnostics captured so far.
+    pub fn diagnostics(&self) -> Vec<OwnedDiagnostic> {
+        self.diagnostics.lock().expect("diagnostics lock").clone()
+    }
+
+    /// Takes diagnostics captured so far.
+    pub fn take(&self) -> Vec<OwnedDiagnostic> {
+        take(&mut *self.diagnostics.lock().expect("diagnostics lock"))
+    }
+
+    /// Stops capturing diagnostics and returns captured ones.
+    pub fn finish(self) -> Vec<OwnedDiagnostic> {
+        self.take()
+    }
+}
+
+impl Drop for DiagnosticCapture<'_> {
+    fn drop(&mut self) {
+        self.context.detach_diagnostic_handler(self.id);
+    }
+}
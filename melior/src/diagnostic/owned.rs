@@ -0,0 +1,80 @@
+use super::{Diagnostic, DiagnosticSeverity};
+use crate::ir::OwnedLocation;
+use std::fmt::{self, Display, Formatter};
+
+/// An owned diagnostic.
+///
+/// Unlike [`Diagnostic`], it outlives a diagnostic handler call.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnedDiagnostic {
+    severity: DiagnosticSeverity,
+    location: OwnedLocation,
+    message: String,
+    notes: Vec<OwnedDiagnostic>,
+}
+
+impl OwnedDiagnostic {
+    /// Creates an owned diagnostic.
+    pub fn new(
+        severity: DiagnosticSeverity,
+        location: OwnedLocation,
+        message: impl Into<String>,
+        notes: Vec<OwnedDiagnostic>,
+    ) -> Self {
+        Self {
+            severity,
+            location,
+            message: message.into(),
+            notes,
+        }
+    }
+
+    /// Returns a severity.
+    pub const fn severity(&self) -> DiagnosticSeverity {
+        self.severity
+    }
+
+    /// Returns a location.
+    pub const fn location(&self) -> &OwnedLocation {
+        &self.location
+    }
+
+    /// Returns a message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns notes.
+    pub fn notes(&self) -> &[OwnedDiagnostic] {
+        &self.notes
+    }
+}
+
+impl From<&Diagnostic<'_>> for OwnedDiagnostic {
+    fn from(diagnostic: &Diagnostic) -> Self {
+        Self::new(
+            diagnostic.severity(),
+            diagnostic.location().into(),
+            diagnostic.to_string(),
+            (0..diagnostic.note_count())
+                .map(|index| Self::from(&diagnostic.note(index).expect("valid note index")))
+                .collect(),
+        )
+    }
+}
+
+impl Display for OwnedDiagnostic {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{}: {}: {}",
+            self.location, self.severity, self.message
+        )?;
+
+        for note in &self.notes {
+            write!(formatter, "\n{note}")?;
+        }
+
+        Ok(())
+    }
+}
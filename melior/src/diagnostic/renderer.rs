@@ -0,0 +1,391 @@
+use super::{OwnedDiagnostic, SourceProvider};
+use crate::ir::OwnedLocation;
+use std::{
+    fmt::{self, Write},
+    iter::repeat,
+};
+
+/// A diagnostic renderer.
+///
+/// It renders diagnostics with snippets of their source files similarly to
+/// `rustc`. Call site and fused locations are rendered as chains of snippets.
+#[derive(Clone, Debug, Default)]
+pub struct DiagnosticRenderer<S> {
+    sources: S,
+}
+
+impl<S: SourceProvider> DiagnosticRenderer<S> {
+    /// Creates a diagnostic renderer.
+    pub const fn new(sources: S) -> Self {
+        Self { sources }
+    }
+
+    /// Returns a source provider.
+    pub const fn sources(&self) -> &S {
+        &self.sources
+    }
+
+    /// Renders a diagnostic and its notes.
+    pub fn render(&self, diagnostic: &OwnedDiagnostic) -> String {
+        let mut string = String::new();
+
+        self.write(&mut string, diagnostic)
+            .expect("infallible write to string");
+
+        string
+    }
+
+    /// Renders diagnostics separated by empty lines.
+    pub fn render_all(&self, diagnostics: &[OwnedDiagnostic]) -> String {
+        diagnostics
+            .iter()
+            .map(|diagnostic| self.render(diagnostic))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Writes a rendered diagnostic and its notes.
+    pub fn write(&self, writer: &mut impl Write, diagnostic: &OwnedDiagnostic) -> fmt::Result {
+        writeln!(
+            writer,
+            "{}: {}",
+            diagnostic.severity(),
+            diagnostic.message()
+        )?;
+
+        let mut frames = vec![];
+        collect_frames(diagnostic.location(), None, &mut frames);
+        let width = frames
+            .iter()
+            .filter_map(|frame| match frame {
+                Frame::Source { line, .. } => Some(line.to_string().len()),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(1);
+
+        for (index, frame) in frames.into_iter().enumerate() {
+            match frame {
+                Frame::Source {
+                    filename,
+                    line,
+                    column,
+                    label,
+                } => {
+                    let arrow = if index == 0 { "-->" } else { ":::" };
+                    writeln!(writer, "{:width$}{arrow} {filename}:{line}:{column}", "")?;
+
+                    if let Some(text) = self.line(filename, line) {
+                        let padding = text
+                            .chars()
+                            .chain(repeat(' '))
+                            .take(column.saturating_sub(1))
+                            .map(|character| if character == '\t' { '\t' } else { ' ' })
+                            .collect::<String>();
+
+                        let label = match label {
+                            Some(Label::Name(name)) => name,
+                            Some(Label::Caller) => "called from here",
+                            None => "",
+                        };
+
+                        writeln!(writer, "{:width$} |", "")?;
+                        write_trimmed(writer, &format!("{line:>width$} | {text}"))?;
+                        write_trimmed(writer, &format!("{:width$} | {padding}^ {label}", ""))?;
+                    }
+                }
+                Frame::Name(name) => writeln!(writer, "{:width$} = in {name}", "")?,
+                Frame::Raw(location) => writeln!(writer, "{:width$}--> {location}", "")?,
+            }
+        }
+
+        for note in diagnostic.notes() {
+            self.write(writer, note)?;
+        }
+
+        Ok(())
+    }
+
+    fn line(&self, filename: &str, line: usize) -> Option<String> {
+        self.sources
+            .source(filename)?
+            .lines()
+            .nth(line.checked_sub(1)?)
+            .map(ToOwned::to_owned)
+    }
+}
+
+fn write_trimmed(writer: &mut impl Write, line: &str) -> fmt::Result {
+    writeln!(writer, "{}", line.trim_end())
+}
+
+enum Frame<'a> {
+    Source {
+        filename: &'a str,
+        line: usize,
+        column: usize,
+        label: Option<Label<'a>>,
+    },
+    Name(&'a str),
+    Raw(&'a str),
+}
+
+#[derive(Clone, Copy)]
+enum Label<'a> {
+    Name(&'a str),
+    Caller,
+}
+
+fn collect_frames<'a>(
+    location: &'a OwnedLocation,
+    label: Option<Label<'a>>,
+    frames: &mut Vec<Frame<'a>>,
+) {
+    match location {
+        OwnedLocation::Unknown => {
+            // Only names are worth rendering without any source locations.
+            if let Some(Label::Name(name)) = label {
+                frames.push(Frame::Name(name));
+            }
+        }
+        OwnedLocation::FileLineColumn {
+            filename,
+            line,
+            column,
+        } => frames.push(Frame::Source {
+            filename: filename.as_str(),
+            line: *line,
+            column: *column,
+            label,
+        }),
+        OwnedLocation::Name { name, child } => {
+            collect_frames(child, Some(Label::Name(name)), frames)
+        }
+        OwnedLocation::CallSite { callee, caller } => {
+            collect_frames(callee, label, frames);
+            collect_frames(caller, Some(Label::Caller), frames);
+        }
+        OwnedLocation::Fused { locations, .. } => {
+            for location in locations {
+                collect_frames(location, label, frames);
+            }
+        }
+        OwnedLocation::Printed(location) => frames.push(Frame::Raw(location)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        diagnostic::DiagnosticSeverity,
+        ir::{attribute::StringAttribute, Location},
+        Context,
+    };
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use std::collections::HashMap;
+
+    fn sources() -> HashMap<&'static str, &'static str> {
+        HashMap::from([(
+            "foo.dsl",
+            indoc!(
+                "
+                let x = 1;
+                let y = foo(x);
+                "
+            ),
+        )])
+    }
+
+    #[test]
+    fn render() {
+        assert_eq!(
+            DiagnosticRenderer::new(sources()).render(&OwnedDiagnostic::new(
+                DiagnosticSeverity::Error,
+                OwnedLocation::FileLineColumn {
+                    filename: "foo.dsl".into(),
+                    line: 2,
+                    column: 9
+                },
+                "unknown function",
+                vec![]
+            )),
+            indoc!(
+                "
+                error: unknown function
+                 --> foo.dsl:2:9
+                  |
+                2 | let y = foo(x);
+                  |         ^
+                "
+            )
+        );
+    }
+
+    #[test]
+    fn render_notes() {
+        assert_eq!(
+            DiagnosticRenderer::new(sources()).render(&OwnedDiagnostic::new(
+                DiagnosticSeverity::Error,
+                OwnedLocation::FileLineColumn {
+                    filename: "foo.dsl".into(),
+                    line: 2,
+                    column: 13
+                },
+                "invalid argument",
+                vec![OwnedDiagnostic::new(
+                    DiagnosticSeverity::Note,
+                    OwnedLocation::FileLineColumn {
+                        filename: "foo.dsl".into(),
+                        line: 1,
+                        column: 5
+                    },
+                    "defined here",
+                    vec![]
+                )]
+            )),
+            indoc!(
+                "
+                error: invalid argument
+                 --> foo.dsl:2:13
+                  |
+                2 | let y = foo(x);
+                  |             ^
+                note: defined here
+                 --> foo.dsl:1:5
+                  |
+                1 | let x = 1;
+                  |     ^
+                "
+            )
+        );
+    }
+
+    #[test]
+    fn render_call_site() {
+        let context = Context::new();
+        let location = Location::call_site(
+            Location::name(&context, "foo", Location::new(&context, "foo.dsl", 2, 9)),
+            Location::call_site(
+                Location::new(&context, "foo.dsl", 1, 1),
+                Location::new(&context, "bar.dsl", 10, 3),
+            ),
+        );
+
+        assert_eq!(
+            DiagnosticRenderer::new(sources()).render(&OwnedDiagnostic::new(
+                DiagnosticSeverity::Warning,
+                location.into(),
+                "deprecated",
+                vec![]
+            )),
+            indoc!(
+                "
+                warning: deprecated
+                  --> foo.dsl:2:9
+                   |
+                 2 | let y = foo(x);
+                   |         ^ foo
+                  ::: foo.dsl:1:1
+                   |
+                 1 | let x = 1;
+                   | ^ called from here
+                  ::: bar.dsl:10:3
+                "
+            )
+        );
+    }
+
+    #[test]
+    fn render_fused() {
+        let context = Context::new();
+        let location = Location::fused(
+            &context,
+            &[
+                Location::new(&context, "foo.dsl", 1, 5),
+                Location::name(&context, "bar", Location::unknown(&context)),
+            ],
+            StringAttribute::new(&context, "baz").into(),
+        );
+
+        assert_eq!(
+            DiagnosticRenderer::new(sources()).render(&OwnedDiagnostic::new(
+                DiagnosticSeverity::Remark,
+                location.into(),
+                "fused",
+                vec![]
+            )),
+            indoc!(
+                "
+                remark: fused
+                 --> foo.dsl:1:5
+                  |
+                1 | let x = 1;
+                  |     ^
+                  = in bar
+                "
+            )
+        );
+    }
+
+    #[test]
+    fn render_unknown() {
+        assert_eq!(
+            DiagnosticRenderer::new(sources()).render(&OwnedDiagnostic::new(
+                DiagnosticSeverity::Error,
+                OwnedLocation::Unknown,
+                "foo",
+                vec![]
+            )),
+            "error: foo\n"
+        );
+    }
+
+    #[test]
+    fn render_printed() {
+        assert_eq!(
+            DiagnosticRenderer::new(sources()).render(&OwnedDiagnostic::new(
+                DiagnosticSeverity::Error,
+                OwnedLocation::Printed("loc(foo)".into()),
+                "bar",
+                vec![]
+            )),
+            "error: bar\n --> loc(foo)\n"
+        );
+    }
+
+    #[test]
+    fn render_all() {
+        let diagnostic = OwnedDiagnostic::new(
+            DiagnosticSeverity::Error,
+            OwnedLocation::Unknown,
+            "foo",
+            vec![],
+        );
+
+        assert_eq!(
+            DiagnosticRenderer::new(sources()).render_all(&[diagnostic.clone(), diagnostic]),
+            "error: foo\n\nerror: foo\n"
+        );
+    }
+
+    #[test]
+    fn render_tab() {
+        assert_eq!(
+            DiagnosticRenderer::new(HashMap::from([("foo", "\tbar")])).render(
+                &OwnedDiagnostic::new(
+                    DiagnosticSeverity::Error,
+                    OwnedLocation::FileLineColumn {
+                        filename: "foo".into(),
+                        line: 1,
+                        column: 2
+                    },
+                    "baz",
+                    vec![]
+                )
+            ),
+            "error: baz\n --> foo:1:2\n  |\n1 | \tbar\n  | \t^\n"
+        );
+    }
+}
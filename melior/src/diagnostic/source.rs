@@ -0,0 +1,93 @@
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fs::read_to_string,
+    hash::BuildHasher,
+    path::{Path, PathBuf},
+};
+
+/// A provider of source files for diagnostic rendering.
+pub trait SourceProvider {
+    /// Returns a source of a file, or `None` if it is not available.
+    fn source(&self, filename: &str) -> Option<Cow<'_, str>>;
+}
+
+impl<T: SourceProvider + ?Sized> SourceProvider for &T {
+    fn source(&self, filename: &str) -> Option<Cow<'_, str>> {
+        (**self).source(filename)
+    }
+}
+
+impl<S: BuildHasher> SourceProvider for HashMap<String, String, S> {
+    fn source(&self, filename: &str) -> Option<Cow<'_, str>> {
+        self.get(filename).map(|source| source.as_str().into())
+    }
+}
+
+impl<S: BuildHasher> SourceProvider for HashMap<&str, &str, S> {
+    fn source(&self, filename: &str) -> Option<Cow<'_, str>> {
+        self.get(filename).map(|&source| source.into())
+    }
+}
+
+/// A source provider reading files in a file system.
+#[derive(Clone, Debug, Default)]
+pub struct FileSystemSourceProvider {
+    root: PathBuf,
+}
+
+impl FileSystemSourceProvider {
+    /// Creates a source provider resolving relative filenames from a root
+    /// directory.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Returns a root directory.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+}
+
+impl SourceProvider for FileSystemSourceProvider {
+    fn source(&self, filename: &str) -> Option<Cow<'_, str>> {
+        read_to_string(self.root.join(filename))
+            .ok()
+            .map(Cow::Owned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        env::temp_dir,
+        fs::{remove_file, write},
+        process,
+    };
+
+    #[test]
+    fn hash_map() {
+        let sources = HashMap::from([("foo".to_owned(), "bar".to_owned())]);
+
+        assert_eq!(sources.source("foo").as_deref(), Some("bar"));
+        assert_eq!(sources.source("baz"), None);
+    }
+
+    #[test]
+    fn file_system() {
+        let root = temp_dir();
+        let filename = format!("melior-source-{}.mlir", process::id());
+        write(root.join(&filename), "foo").unwrap();
+        let source = FileSystemSourceProvider::new(&root)
+            .source(&filename)
+            .map(Cow::into_owned);
+        remove_file(root.join(&filename)).unwrap();
+
+        assert_eq!(source.as_deref(), Some("foo"));
+        assert_eq!(
+            FileSystemSourceProvider::new(&root).source("melior-no-such-file.mlir"),
+            None
+        );
+    }
+}
@@ -11,6 +11,7 @@ use crate::{
 // spell-checker: disable
 
 /// Creates an `arith.constant` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn constant<'c>(
     context: &'c Context,
     value: Attribute<'c>,
@@ -44,6 +45,7 @@ pub enum CmpfPredicate {
 }
 
 /// Creates an `arith.cmpf` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn cmpf<'c>(
     context: &'c Context,
     predicate: CmpfPredicate,
@@ -69,6 +71,7 @@ pub enum CmpiPredicate {
 }
 
 /// Creates an `arith.cmpi` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn cmpi<'c>(
     context: &'c Context,
     predicate: CmpiPredicate,
@@ -79,6 +82,7 @@ pub fn cmpi<'c>(
     cmp(context, "arith.cmpi", predicate as i64, lhs, rhs, location)
 }
 
+#[cfg_attr(feature = "track-caller", track_caller)]
 fn cmp<'c>(
     context: &'c Context,
     name: &str,
@@ -99,6 +103,7 @@ fn cmp<'c>(
 }
 
 /// Creates an `arith.select` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn select<'c>(
     condition: Value<'c, '_>,
     true_value: Value<'c, '_>,
@@ -14,6 +14,7 @@ use crate::{
 };
 
 /// Creates a `cf.assert` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn assert<'c>(
     context: &'c Context,
     argument: Value<'c, '_>,
@@ -31,6 +32,7 @@ pub fn assert<'c>(
 }
 
 /// Creates a `cf.br` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn br<'c>(
     successor: &Block<'c>,
     destination_operands: &[Value<'c, '_>],
@@ -44,6 +46,7 @@ pub fn br<'c>(
 }
 
 /// Creates a `cf.cond_br` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn cond_br<'c>(
     context: &'c Context,
     condition: Value<'c, '_>,
@@ -79,6 +82,7 @@ pub fn cond_br<'c>(
 }
 
 /// Creates a `cf.switch` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn switch<'c>(
     context: &'c Context,
     case_values: &[i64],
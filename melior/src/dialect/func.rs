@@ -11,6 +11,7 @@ use crate::{
 };
 
 /// Create a `func.call` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn call<'c>(
     context: &'c Context,
     function: FlatSymbolRefAttribute<'c>,
@@ -27,6 +28,7 @@ pub fn call<'c>(
 }
 
 /// Create a `func.call_indirect` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn call_indirect<'c>(
     function: Value<'c, '_>,
     arguments: &[Value<'c, '_>],
@@ -42,6 +44,7 @@ pub fn call_indirect<'c>(
 }
 
 /// Create a `func.constant` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn constant<'c>(
     context: &'c Context,
     function: FlatSymbolRefAttribute<'c>,
@@ -56,6 +59,7 @@ pub fn constant<'c>(
 }
 
 /// Create a `func.func` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn func<'c>(
     context: &'c Context,
     name: StringAttribute<'c>,
@@ -76,6 +80,7 @@ pub fn func<'c>(
 }
 
 /// Create a `func.return` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn r#return<'c>(operands: &[Value<'c, '_>], location: Location<'c>) -> Operation<'c> {
     OperationBuilder::new("func.return", location)
         .add_operands(operands)
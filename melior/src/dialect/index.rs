@@ -12,6 +12,7 @@ use crate::{
 // spell-checker: disable
 
 /// Creates an `index.constant` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn constant<'c>(
     context: &'c Context,
     value: IntegerAttribute<'c>,
@@ -25,6 +26,7 @@ pub fn constant<'c>(
 }
 
 /// Creates an `index.cmp` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn cmp<'c>(
     context: &'c Context,
     predicate: CmpiPredicate,
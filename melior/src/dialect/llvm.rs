@@ -23,6 +23,7 @@ pub mod r#type;
 // spell-checker: disable
 
 /// Creates a `llvm.extractvalue` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn extract_value<'c>(
     context: &'c Context,
     container: Value<'c, '_>,
@@ -39,6 +40,7 @@ pub fn extract_value<'c>(
 }
 
 /// Creates a `llvm.getelementptr` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn get_element_ptr<'c>(
     context: &'c Context,
     ptr: Value<'c, '_>,
@@ -65,6 +67,7 @@ pub fn get_element_ptr<'c>(
 }
 
 /// Creates a `llvm.getelementptr` operation with dynamic indices.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn get_element_ptr_dynamic<'c, const N: usize>(
     context: &'c Context,
     ptr: Value<'c, '_>,
@@ -92,6 +95,7 @@ pub fn get_element_ptr_dynamic<'c, const N: usize>(
 }
 
 /// Creates a `llvm.insertvalue` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn insert_value<'c>(
     context: &'c Context,
     container: Value<'c, '_>,
@@ -108,6 +112,7 @@ pub fn insert_value<'c>(
 }
 
 /// Creates a `llvm.mlir.undef` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn undef<'c>(result_type: Type<'c>, location: Location<'c>) -> Operation<'c> {
     OperationBuilder::new("llvm.mlir.undef", location)
         .add_results(&[result_type])
@@ -116,6 +121,7 @@ pub fn undef<'c>(result_type: Type<'c>, location: Location<'c>) -> Operation<'c>
 }
 
 /// Creates a `llvm.mlir.poison` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn poison<'c>(result_type: Type<'c>, location: Location<'c>) -> Operation<'c> {
     OperationBuilder::new("llvm.mlir.poison", location)
         .add_results(&[result_type])
@@ -124,6 +130,7 @@ pub fn poison<'c>(result_type: Type<'c>, location: Location<'c>) -> Operation<'c
 }
 
 /// Creates a zero value.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn zero<'c>(r#type: Type<'c>, location: Location<'c>) -> Operation<'c> {
     OperationBuilder::new("llvm.mlir.zero", location)
         .add_results(&[r#type])
@@ -133,11 +140,13 @@ pub fn zero<'c>(r#type: Type<'c>, location: Location<'c>) -> Operation<'c> {
 
 /// Creates a null pointer.
 #[deprecated]
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn nullptr<'c>(ptr_type: Type<'c>, location: Location<'c>) -> Operation<'c> {
     zero(ptr_type, location)
 }
 
 /// Creates a `llvm.unreachable` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn unreachable(location: Location) -> Operation {
     OperationBuilder::new("llvm.unreachable", location)
         .build()
@@ -145,6 +154,7 @@ pub fn unreachable(location: Location) -> Operation {
 }
 
 /// Creates a `llvm.bitcast` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn bitcast<'c>(
     argument: Value<'c, '_>,
     result: Type<'c>,
@@ -158,6 +168,7 @@ pub fn bitcast<'c>(
 }
 
 /// Creates a `llvm.alloca` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn alloca<'c>(
     context: &'c Context,
     array_size: Value<'c, '_>,
@@ -174,6 +185,7 @@ pub fn alloca<'c>(
 }
 
 /// Creates a `llvm.store` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn store<'c>(
     context: &'c Context,
     value: Value<'c, '_>,
@@ -189,6 +201,7 @@ pub fn store<'c>(
 }
 
 /// Creates a `llvm.load` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn load<'c>(
     context: &'c Context,
     addr: Value<'c, '_>,
@@ -205,6 +218,7 @@ pub fn load<'c>(
 }
 
 /// Create a `llvm.func` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn func<'c>(
     context: &'c Context,
     name: StringAttribute<'c>,
@@ -225,6 +239,7 @@ pub fn func<'c>(
 }
 
 // Creates a `llvm.return` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn r#return<'c>(value: Option<Value<'c, '_>>, location: Location<'c>) -> Operation<'c> {
     let mut builder = OperationBuilder::new("llvm.return", location);
 
@@ -236,6 +251,7 @@ pub fn r#return<'c>(value: Option<Value<'c, '_>>, location: Location<'c>) -> Ope
 }
 
 /// Creates a `llvm.call_intrinsic` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn call_intrinsic<'c>(
     context: &'c Context,
     intrin: StringAttribute<'c>,
@@ -252,6 +268,7 @@ pub fn call_intrinsic<'c>(
 }
 
 /// Creates a `llvm.intr.ctlz` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn intr_ctlz<'c>(
     context: &'c Context,
     value: Value<'c, '_>,
@@ -272,6 +289,7 @@ pub fn intr_ctlz<'c>(
 }
 
 /// Creates a `llvm.intr.ctlz` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn intr_cttz<'c>(
     context: &'c Context,
     value: Value<'c, '_>,
@@ -292,6 +310,7 @@ pub fn intr_cttz<'c>(
 }
 
 /// Creates a `llvm.intr.ctlz` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn intr_ctpop<'c>(
     value: Value<'c, '_>,
     result_type: Type<'c>,
@@ -305,6 +324,7 @@ pub fn intr_ctpop<'c>(
 }
 
 /// Creates a `llvm.intr.bswap` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn intr_bswap<'c>(
     value: Value<'c, '_>,
     result_type: Type<'c>,
@@ -318,6 +338,7 @@ pub fn intr_bswap<'c>(
 }
 
 /// Creates a `llvm.intr.bitreverse` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn intr_bitreverse<'c>(
     value: Value<'c, '_>,
     result_type: Type<'c>,
@@ -331,6 +352,7 @@ pub fn intr_bitreverse<'c>(
 }
 
 /// Creates a `llvm.intr.abs` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn intr_abs<'c>(
     context: &'c Context,
     value: Value<'c, '_>,
@@ -354,6 +376,7 @@ pub fn intr_abs<'c>(
 }
 
 /// Creates a `llvm.zext` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn zext<'c>(
     value: Value<'c, '_>,
     result_type: Type<'c>,
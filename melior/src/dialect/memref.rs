@@ -14,6 +14,7 @@ use crate::{
 };
 
 /// Create a `memref.alloc` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn alloc<'c>(
     context: &'c Context,
     r#type: MemRefType<'c>,
@@ -34,6 +35,7 @@ pub fn alloc<'c>(
 }
 
 /// Create a `memref.alloca` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn alloca<'c>(
     context: &'c Context,
     r#type: MemRefType<'c>,
@@ -53,6 +55,7 @@ pub fn alloca<'c>(
     )
 }
 
+#[cfg_attr(feature = "track-caller", track_caller)]
 fn allocate<'c>(
     context: &'c Context,
     name: &str,
@@ -83,6 +86,7 @@ fn allocate<'c>(
 }
 
 /// Create a `memref.cast` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn cast<'c>(
     value: Value<'c, '_>,
     r#type: MemRefType<'c>,
@@ -96,6 +100,7 @@ pub fn cast<'c>(
 }
 
 /// Create a `memref.dealloc` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn dealloc<'c>(value: Value<'c, '_>, location: Location<'c>) -> Operation<'c> {
     OperationBuilder::new("memref.dealloc", location)
         .add_operands(&[value])
@@ -104,6 +109,7 @@ pub fn dealloc<'c>(value: Value<'c, '_>, location: Location<'c>) -> Operation<'c
 }
 
 /// Create a `memref.dim` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn dim<'c>(
     value: Value<'c, '_>,
     index: Value<'c, '_>,
@@ -117,6 +123,7 @@ pub fn dim<'c>(
 }
 
 /// Create a `memref.get_global` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn get_global<'c>(
     context: &'c Context,
     name: &str,
@@ -134,6 +141,7 @@ pub fn get_global<'c>(
 }
 
 /// Create a `memref.view` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn view<'c>(
     context: &'c Context,
     source: Value<'c, '_>,
@@ -157,6 +165,7 @@ pub fn view<'c>(
 
 /// Create a `memref.subview` operation.
 #[allow(clippy::too_many_arguments)]
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn subview<'c>(
     context: &'c Context,
     source: Value<'c, '_>,
@@ -208,6 +217,7 @@ pub fn subview<'c>(
 
 /// Create a `memref.global` operation.
 #[allow(clippy::too_many_arguments)]
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn global<'c>(
     context: &'c Context,
     name: &str,
@@ -256,6 +266,7 @@ pub fn global<'c>(
 }
 
 /// Create a `memref.load` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn load<'c>(
     memref: Value<'c, '_>,
     indices: &[Value<'c, '_>],
@@ -270,6 +281,7 @@ pub fn load<'c>(
 }
 
 /// Create a `memref.rank` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn rank<'c>(value: Value<'c, '_>, location: Location<'c>) -> Operation<'c> {
     OperationBuilder::new("memref.rank", location)
         .add_operands(&[value])
@@ -279,6 +291,7 @@ pub fn rank<'c>(value: Value<'c, '_>, location: Location<'c>) -> Operation<'c> {
 }
 
 /// Create a `memref.store` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn store<'c>(
     value: Value<'c, '_>,
     memref: Value<'c, '_>,
@@ -293,6 +306,7 @@ pub fn store<'c>(
 }
 
 /// Create a `memref.realloc` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn realloc<'c>(
     context: &'c Context,
     value: Value<'c, '_>,
@@ -9,6 +9,7 @@ use crate::{
 };
 
 /// Creates a `scf.condition` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn condition<'c>(
     condition: Value<'c, '_>,
     values: &[Value<'c, '_>],
@@ -22,6 +23,7 @@ pub fn condition<'c>(
 }
 
 /// Creates a `scf.execute_region` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn execute_region<'c>(
     result_types: &[Type<'c>],
     region: Region<'c>,
@@ -35,6 +37,7 @@ pub fn execute_region<'c>(
 }
 
 /// Creates a `scf.for` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn r#for<'c>(
     start: Value<'c, '_>,
     end: Value<'c, '_>,
@@ -50,6 +53,7 @@ pub fn r#for<'c>(
 }
 
 /// Creates a `scf.if` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn r#if<'c>(
     condition: Value<'c, '_>,
     result_types: &[Type<'c>],
@@ -66,6 +70,7 @@ pub fn r#if<'c>(
 }
 
 /// Creates a `scf.index_switch` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn index_switch<'c>(
     context: &'c Context,
     condition: Value<'c, '_>,
@@ -84,6 +89,7 @@ pub fn index_switch<'c>(
 }
 
 /// Creates a `scf.while` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn r#while<'c>(
     initial_values: &[Value<'c, '_>],
     result_types: &[Type<'c>],
@@ -100,6 +106,7 @@ pub fn r#while<'c>(
 }
 
 /// Creates a `scf.yield` operation.
+#[cfg_attr(feature = "track-caller", track_caller)]
 pub fn r#yield<'c>(values: &[Value<'c, '_>], location: Location<'c>) -> Operation<'c> {
     OperationBuilder::new("scf.yield", location)
         .add_operands(values)
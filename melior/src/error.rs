@@ -1,6 +1,8 @@
+use crate::diagnostic::OwnedDiagnostic;
 use std::{
     convert::Infallible,
     error,
+    ffi::NulError,
     fmt::{self, Display, Formatter},
     str::Utf8Error,
 };
@@ -12,25 +14,67 @@ pub enum Error {
     AttributeNotFound(String),
     AttributeParse(String),
     BlockArgumentExpected(String),
+    BlockInUse(String),
+    BlockNotFound(String),
+    ConstraintExpected,
+    DimensionCountMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    ElementCountMismatch {
+        expected: usize,
+        actual: usize,
+    },
     ElementExpected {
         r#type: &'static str,
         value: String,
     },
+    InvalidRawBuffer(String),
     InvokeFunction,
+    Nul(NulError),
     OperationBuild,
     OperandNotFound(&'static str),
     OperationResultExpected(String),
+    OperationVerify(Vec<OwnedDiagnostic>),
     PositionOutOfBounds {
         name: &'static str,
         value: String,
         index: usize,
     },
+    ParentBlockNotFound(String),
+    Parse {
+        r#type: &'static str,
+        diagnostics: Vec<OwnedDiagnostic>,
+    },
+    PassFailure(Vec<OwnedDiagnostic>),
     ParsePassPipeline(String),
+    ReadFile {
+        path: String,
+        message: String,
+    },
+    ResultCountMismatch {
+        expected: usize,
+        actual: usize,
+    },
     ResultNotFound(&'static str),
+    ResultTypeMismatch {
+        expected: String,
+        actual: String,
+    },
     RunPass,
+    StrideOverflow(String),
+    SymbolConflict(String),
+    SymbolUseReplacement(String),
+    TopLevelOperationCount {
+        path: String,
+        count: usize,
+    },
     TypeExpected(&'static str, String),
     UnknownDiagnosticSeverity(u32),
+    UnknownSymbolVisibility(String),
     Utf8(Utf8Error),
+    ValueInUse(String),
+    WriteBytecode(String),
 }
 
 impl Display for Error {
@@ -48,10 +92,37 @@ impl Display for Error {
             Self::BlockArgumentExpected(value) => {
                 write!(formatter, "block argument expected: {value}")
             }
+            Self::BlockInUse(block) => {
+                write!(formatter, "block still in use: {block}")
+            }
+            Self::BlockNotFound(block) => {
+                write!(formatter, "block not found in region: {block}")
+            }
+            Self::ConstraintExpected => {
+                write!(formatter, "at least one integer set constraint expected")
+            }
+            Self::DimensionCountMismatch { expected, actual } => {
+                write!(
+                    formatter,
+                    "dimension count mismatch: expected {expected} but got {actual}"
+                )
+            }
+            Self::ElementCountMismatch { expected, actual } => {
+                write!(
+                    formatter,
+                    "element count mismatch: expected {expected} but got {actual}"
+                )
+            }
             Self::ElementExpected { r#type, value } => {
                 write!(formatter, "element of {type} type expected: {value}")
             }
+            Self::InvalidRawBuffer(r#type) => {
+                write!(formatter, "invalid raw buffer for type: {type}")
+            }
             Self::InvokeFunction => write!(formatter, "failed to invoke JIT-compiled function"),
+            Self::Nul(error) => {
+                write!(formatter, "{}", error)
+            }
             Self::OperationBuild => {
                 write!(formatter, "operation build failed")
             }
@@ -61,31 +132,102 @@ impl Display for Error {
             Self::OperationResultExpected(value) => {
                 write!(formatter, "operation result expected: {value}")
             }
+            Self::OperationVerify(diagnostics) => {
+                write!(formatter, "failed to verify operation:")?;
+                write_diagnostics(formatter, diagnostics)
+            }
+            Self::ParentBlockNotFound(operation) => {
+                write!(formatter, "parent block not found: {operation}")
+            }
+            Self::Parse {
+                r#type,
+                diagnostics,
+            } => {
+                write!(formatter, "failed to parse {type}:")?;
+                write_diagnostics(formatter, diagnostics)
+            }
+            Self::PassFailure(diagnostics) => {
+                write!(formatter, "failed to run pass:")?;
+                write_diagnostics(formatter, diagnostics)
+            }
             Self::ParsePassPipeline(message) => {
                 write!(formatter, "failed to parse pass pipeline:\n{}", message)
             }
             Self::PositionOutOfBounds { name, value, index } => {
                 write!(formatter, "{name} position {index} out of bounds: {value}")
             }
+            Self::ReadFile { path, message } => {
+                write!(formatter, "failed to read file {path}: {message}")
+            }
+            Self::ResultCountMismatch { expected, actual } => {
+                write!(
+                    formatter,
+                    "result count mismatch: expected {expected} but got {actual}"
+                )
+            }
             Self::ResultNotFound(name) => {
                 write!(formatter, "result {name} not found")
             }
+            Self::ResultTypeMismatch { expected, actual } => {
+                write!(
+                    formatter,
+                    "result type mismatch: expected {expected} but got {actual}"
+                )
+            }
             Self::RunPass => write!(formatter, "failed to run pass"),
+            Self::StrideOverflow(r#type) => {
+                write!(formatter, "stride overflow: {type}")
+            }
+            Self::SymbolConflict(symbol) => {
+                write!(formatter, "symbol already exists: {symbol}")
+            }
+            Self::SymbolUseReplacement(symbol) => {
+                write!(formatter, "failed to replace uses of symbol {symbol}")
+            }
+            Self::TopLevelOperationCount { path, count } => {
+                write!(
+                    formatter,
+                    "single top-level operation expected in file {path} but got {count}"
+                )
+            }
             Self::TypeExpected(r#type, actual) => {
                 write!(formatter, "{type} type expected: {actual}")
             }
             Self::UnknownDiagnosticSeverity(severity) => {
                 write!(formatter, "unknown diagnostic severity: {severity}")
             }
+            Self::UnknownSymbolVisibility(visibility) => {
+                write!(formatter, "unknown symbol visibility: {visibility}")
+            }
             Self::Utf8(error) => {
                 write!(formatter, "{}", error)
             }
+            Self::ValueInUse(value) => {
+                write!(formatter, "value still in use: {value}")
+            }
+            Self::WriteBytecode(message) => {
+                write!(formatter, "failed to write bytecode: {message}")
+            }
         }
     }
 }
 
+fn write_diagnostics(formatter: &mut Formatter, diagnostics: &[OwnedDiagnostic]) -> fmt::Result {
+    for diagnostic in diagnostics {
+        write!(formatter, "\n{diagnostic}")?;
+    }
+
+    Ok(())
+}
+
 impl error::Error for Error {}
 
+impl From<NulError> for Error {
+    fn from(error: NulError) -> Self {
+        Self::Nul(error)
+    }
+}
+
 impl From<Utf8Error> for Error {
     fn from(error: Utf8Error) -> Self {
         Self::Utf8(error)
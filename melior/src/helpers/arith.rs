@@ -15,6 +15,7 @@ use core::fmt::Display;
 macro_rules! binary_operation_declaration {
     ($name:ident, $documentation:literal) => {
         #[doc=$documentation]
+        #[cfg_attr(feature = "track-caller", track_caller)]
         fn $name(
             &self,
             lhs: Value<'c, '_>,
@@ -27,6 +28,7 @@ macro_rules! binary_operation_declaration {
 macro_rules! binary_operation {
     ($name:ident) => {
         #[inline]
+        #[cfg_attr(feature = "track-caller", track_caller)]
         fn $name(
             &self,
             lhs: Value<'c, '_>,
@@ -53,6 +55,7 @@ pub trait ArithBlockExt<'c>: BuiltinBlockExt<'c> {
     binary_operation_declaration!(xori, "Creates an `arith.xori` operation.");
 
     /// Creates an `arith.cmpi` operation.
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn cmpi(
         &self,
         context: &'c Context,
@@ -63,6 +66,7 @@ pub trait ArithBlockExt<'c>: BuiltinBlockExt<'c> {
     ) -> Result<Value<'c, '_>, Error>;
 
     /// Creates an `arith.extui` operation.
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn extui(
         &self,
         lhs: Value<'c, '_>,
@@ -71,6 +75,7 @@ pub trait ArithBlockExt<'c>: BuiltinBlockExt<'c> {
     ) -> Result<Value<'c, '_>, Error>;
 
     /// Creates an `arith.extui` operation.
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn extsi(
         &self,
         lhs: Value<'c, '_>,
@@ -79,6 +84,7 @@ pub trait ArithBlockExt<'c>: BuiltinBlockExt<'c> {
     ) -> Result<Value<'c, '_>, Error>;
 
     /// Creates an `arith.extui` operation.
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn trunci(
         &self,
         lhs: Value<'c, '_>,
@@ -87,6 +93,7 @@ pub trait ArithBlockExt<'c>: BuiltinBlockExt<'c> {
     ) -> Result<Value<'c, '_>, Error>;
 
     /// Creates a constant of the given integer bit width.
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn const_int(
         &self,
         context: &'c Context,
@@ -96,6 +103,7 @@ pub trait ArithBlockExt<'c>: BuiltinBlockExt<'c> {
     ) -> Result<Value<'c, '_>, Error>;
 
     /// Creates a constant of the given integer type.
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn const_int_from_type(
         &self,
         context: &'c Context,
@@ -119,6 +127,7 @@ impl<'c> ArithBlockExt<'c> for Block<'c> {
     binary_operation!(xori);
 
     #[inline]
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn cmpi(
         &self,
         context: &'c Context,
@@ -131,6 +140,7 @@ impl<'c> ArithBlockExt<'c> for Block<'c> {
     }
 
     #[inline]
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn extsi(
         &self,
         value: Value<'c, '_>,
@@ -141,6 +151,7 @@ impl<'c> ArithBlockExt<'c> for Block<'c> {
     }
 
     #[inline]
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn extui(
         &self,
         value: Value<'c, '_>,
@@ -151,6 +162,7 @@ impl<'c> ArithBlockExt<'c> for Block<'c> {
     }
 
     #[inline]
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn trunci(
         &self,
         value: Value<'c, '_>,
@@ -161,6 +173,7 @@ impl<'c> ArithBlockExt<'c> for Block<'c> {
     }
 
     #[inline]
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn const_int(
         &self,
         context: &'c Context,
@@ -177,6 +190,7 @@ impl<'c> ArithBlockExt<'c> for Block<'c> {
     }
 
     #[inline]
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn const_int_from_type(
         &self,
         context: &'c Context,
@@ -25,6 +25,7 @@ pub enum GepIndex<'c, 'a> {
 /// A block extension for an `llvm` dialect.
 pub trait LlvmBlockExt<'c>: BuiltinBlockExt<'c> + ArithBlockExt<'c> {
     /// Creates an `llvm.extractvalue` operation.
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn extract_value(
         &self,
         context: &'c Context,
@@ -35,6 +36,7 @@ pub trait LlvmBlockExt<'c>: BuiltinBlockExt<'c> + ArithBlockExt<'c> {
     ) -> Result<Value<'c, '_>, Error>;
 
     /// Creates an `llvm.insertvalue` operation.
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn insert_value(
         &self,
         context: &'c Context,
@@ -46,6 +48,7 @@ pub trait LlvmBlockExt<'c>: BuiltinBlockExt<'c> + ArithBlockExt<'c> {
 
     /// Creates an `llvm.insertvalue` operation that insert multiple elements into an aggregate
     /// from the first index.
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn insert_values<'block>(
         &'block self,
         context: &'c Context,
@@ -55,6 +58,7 @@ pub trait LlvmBlockExt<'c>: BuiltinBlockExt<'c> + ArithBlockExt<'c> {
     ) -> Result<Value<'c, 'block>, Error>;
 
     /// Creates an `llvm.load` operation.
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn load(
         &self,
         context: &'c Context,
@@ -64,6 +68,7 @@ pub trait LlvmBlockExt<'c>: BuiltinBlockExt<'c> + ArithBlockExt<'c> {
     ) -> Result<Value<'c, '_>, Error>;
 
     /// Creates an `llvm.alloca` operation.
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn alloca(
         &self,
         context: &'c Context,
@@ -74,6 +79,7 @@ pub trait LlvmBlockExt<'c>: BuiltinBlockExt<'c> + ArithBlockExt<'c> {
     ) -> Result<Value<'c, '_>, Error>;
 
     /// Creates an `llvm.alloca` operation that allocates one element.
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn alloca1(
         &self,
         context: &'c Context,
@@ -83,6 +89,7 @@ pub trait LlvmBlockExt<'c>: BuiltinBlockExt<'c> + ArithBlockExt<'c> {
     ) -> Result<Value<'c, '_>, Error>;
 
     /// Creates an `llvm.alloca` operation that allocates one element of the given size of an integer.
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn alloca_int(
         &self,
         context: &'c Context,
@@ -92,6 +99,7 @@ pub trait LlvmBlockExt<'c>: BuiltinBlockExt<'c> + ArithBlockExt<'c> {
     ) -> Result<Value<'c, '_>, Error>;
 
     /// Creates an `llvm.store` operation.
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn store(
         &self,
         context: &'c Context,
@@ -101,6 +109,7 @@ pub trait LlvmBlockExt<'c>: BuiltinBlockExt<'c> + ArithBlockExt<'c> {
     ) -> Result<(), Error>;
 
     /// Creates an `llvm.memcpy` operation.
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn memcpy(
         &self,
         context: &'c Context,
@@ -113,6 +122,7 @@ pub trait LlvmBlockExt<'c>: BuiltinBlockExt<'c> + ArithBlockExt<'c> {
     /// Creates an `llvm.getelementptr` operation.
     ///
     /// This method allows combining both compile time indexes and runtime value indexes.
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn gep(
         &self,
         context: &'c Context,
@@ -125,6 +135,7 @@ pub trait LlvmBlockExt<'c>: BuiltinBlockExt<'c> + ArithBlockExt<'c> {
 
 impl<'c> LlvmBlockExt<'c> for Block<'c> {
     #[inline]
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn extract_value(
         &self,
         context: &'c Context,
@@ -146,6 +157,7 @@ impl<'c> LlvmBlockExt<'c> for Block<'c> {
     }
 
     #[inline]
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn insert_value(
         &self,
         context: &'c Context,
@@ -168,6 +180,7 @@ impl<'c> LlvmBlockExt<'c> for Block<'c> {
     }
 
     #[inline]
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn insert_values<'block>(
         &'block self,
         context: &'c Context,
@@ -182,6 +195,7 @@ impl<'c> LlvmBlockExt<'c> for Block<'c> {
     }
 
     #[inline]
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn store(
         &self,
         context: &'c Context,
@@ -194,6 +208,7 @@ impl<'c> LlvmBlockExt<'c> for Block<'c> {
     }
 
     #[inline]
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn load(
         &self,
         context: &'c Context,
@@ -205,6 +220,7 @@ impl<'c> LlvmBlockExt<'c> for Block<'c> {
     }
 
     #[inline]
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn memcpy(
         &self,
         context: &'c Context,
@@ -227,6 +243,7 @@ impl<'c> LlvmBlockExt<'c> for Block<'c> {
     }
 
     #[inline]
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn alloca(
         &self,
         context: &'c Context,
@@ -253,6 +270,7 @@ impl<'c> LlvmBlockExt<'c> for Block<'c> {
     }
 
     #[inline]
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn alloca1(
         &self,
         context: &'c Context,
@@ -270,6 +288,7 @@ impl<'c> LlvmBlockExt<'c> for Block<'c> {
     }
 
     #[inline]
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn alloca_int(
         &self,
         context: &'c Context,
@@ -286,6 +305,7 @@ impl<'c> LlvmBlockExt<'c> for Block<'c> {
     }
 
     #[inline]
+    #[cfg_attr(feature = "track-caller", track_caller)]
     fn gep(
         &self,
         context: &'c Context,
@@ -1,25 +1,31 @@
 //! IR objects and builders.
 
+mod affine_expr;
 mod affine_map;
 pub mod attribute;
 pub mod block;
 mod identifier;
+mod integer_set;
 mod location;
 mod module;
 pub mod operation;
 mod region;
+mod symbol_table;
 pub mod r#type;
 mod value;
 
 pub use self::{
+    affine_expr::AffineExpr,
     affine_map::AffineMap,
-    attribute::{Attribute, AttributeLike},
+    attribute::{Attribute, AttributeClass, AttributeLike},
     block::{Block, BlockLike, BlockRef},
     identifier::Identifier,
-    location::Location,
-    module::Module,
+    integer_set::IntegerSet,
+    location::{Location, LocationKind, OwnedLocation},
+    module::{Module, OwnedModule},
     operation::{Operation, OperationRef},
-    r#type::{ShapedTypeLike, Type, TypeLike},
+    r#type::{ShapedTypeLike, Type, TypeClass, TypeLike},
     region::{Region, RegionLike, RegionRef},
+    symbol_table::{SymbolTable, SymbolVisibility},
     value::{Value, ValueLike},
 };
@@ -0,0 +1,429 @@
+use super::AffineMap;
+use crate::{
+    context::{Context, ContextRef},
+    utility::print_callback,
+};
+use mlir_sys::{
+    mlirAffineAddExprGet, mlirAffineBinaryOpExprGetLHS, mlirAffineBinaryOpExprGetRHS,
+    mlirAffineCeilDivExprGet, mlirAffineConstantExprGet, mlirAffineConstantExprGetValue,
+    mlirAffineDimExprGet, mlirAffineDimExprGetPosition, mlirAffineExprCompose, mlirAffineExprDump,
+    mlirAffineExprEqual, mlirAffineExprGetContext, mlirAffineExprGetLargestKnownDivisor,
+    mlirAffineExprIsAAdd, mlirAffineExprIsABinary, mlirAffineExprIsACeilDiv,
+    mlirAffineExprIsAConstant, mlirAffineExprIsADim, mlirAffineExprIsAFloorDiv,
+    mlirAffineExprIsAMod, mlirAffineExprIsAMul, mlirAffineExprIsASymbol,
+    mlirAffineExprIsFunctionOfDim, mlirAffineExprIsMultipleOf, mlirAffineExprIsPureAffine,
+    mlirAffineExprIsSymbolicOrConstant, mlirAffineExprPrint, mlirAffineFloorDivExprGet,
+    mlirAffineModExprGet, mlirAffineMulExprGet, mlirAffineSymbolExprGet,
+    mlirAffineSymbolExprGetPosition, MlirAffineExpr,
+};
+use std::{
+    ffi::c_void,
+    fmt::{self, Debug, Display, Formatter},
+    marker::PhantomData,
+    ops::{Add, Mul, Rem, Sub},
+};
+
+/// An affine expression.
+#[derive(Clone, Copy)]
+pub struct AffineExpr<'c> {
+    raw: MlirAffineExpr,
+    _context: PhantomData<&'c Context>,
+}
+
+impl<'c> AffineExpr<'c> {
+    /// Creates a dimension expression.
+    pub fn dimension(context: &'c Context, position: usize) -> Self {
+        unsafe { Self::from_raw(mlirAffineDimExprGet(context.to_raw(), position as isize)) }
+    }
+
+    /// Creates a symbol expression.
+    pub fn symbol(context: &'c Context, position: usize) -> Self {
+        unsafe { Self::from_raw(mlirAffineSymbolExprGet(context.to_raw(), position as isize)) }
+    }
+
+    /// Creates a constant expression.
+    pub fn constant(context: &'c Context, value: i64) -> Self {
+        unsafe { Self::from_raw(mlirAffineConstantExprGet(context.to_raw(), value)) }
+    }
+
+    /// Creates a floor division expression.
+    pub fn floor_div(self, other: Self) -> Self {
+        unsafe { Self::from_raw(mlirAffineFloorDivExprGet(self.raw, other.raw)) }
+    }
+
+    /// Creates a ceiling division expression.
+    pub fn ceil_div(self, other: Self) -> Self {
+        unsafe { Self::from_raw(mlirAffineCeilDivExprGet(self.raw, other.raw)) }
+    }
+
+    /// Returns a context.
+    pub fn context(&self) -> ContextRef<'c> {
+        unsafe { ContextRef::from_raw(mlirAffineExprGetContext(self.raw)) }
+    }
+
+    /// Returns a position of a dimension or symbol expression.
+    pub fn position(&self) -> Option<usize> {
+        if self.is_dimension() {
+            Some(unsafe { mlirAffineDimExprGetPosition(self.raw) } as usize)
+        } else if self.is_symbol() {
+            Some(unsafe { mlirAffineSymbolExprGetPosition(self.raw) } as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a value of a constant expression.
+    pub fn value(&self) -> Option<i64> {
+        self.is_constant()
+            .then(|| unsafe { mlirAffineConstantExprGetValue(self.raw) })
+    }
+
+    /// Returns a left-hand side operand of a binary expression.
+    pub fn lhs(&self) -> Option<Self> {
+        self.is_binary()
+            .then(|| unsafe { Self::from_raw(mlirAffineBinaryOpExprGetLHS(self.raw)) })
+    }
+
+    /// Returns a right-hand side operand of a binary expression.
+    pub fn rhs(&self) -> Option<Self> {
+        self.is_binary()
+            .then(|| unsafe { Self::from_raw(mlirAffineBinaryOpExprGetRHS(self.raw)) })
+    }
+
+    /// Returns the largest known integral divisor.
+    pub fn largest_known_divisor(&self) -> i64 {
+        unsafe { mlirAffineExprGetLargestKnownDivisor(self.raw) }
+    }
+
+    /// Returns `true` if an expression is a multiple of a factor.
+    pub fn is_multiple_of(&self, factor: i64) -> bool {
+        unsafe { mlirAffineExprIsMultipleOf(self.raw, factor) }
+    }
+
+    /// Returns `true` if an expression involves a dimension at a position.
+    pub fn is_function_of_dimension(&self, position: usize) -> bool {
+        unsafe { mlirAffineExprIsFunctionOfDim(self.raw, position as isize) }
+    }
+
+    /// Returns `true` if an expression is made out of only symbols and
+    /// constants.
+    pub fn is_symbolic_or_constant(&self) -> bool {
+        unsafe { mlirAffineExprIsSymbolicOrConstant(self.raw) }
+    }
+
+    /// Returns `true` if an expression is a pure affine expression.
+    pub fn is_pure_affine(&self) -> bool {
+        unsafe { mlirAffineExprIsPureAffine(self.raw) }
+    }
+
+    /// Returns `true` if an expression is a dimension expression.
+    pub fn is_dimension(&self) -> bool {
+        unsafe { mlirAffineExprIsADim(self.raw) }
+    }
+
+    /// Returns `true` if an expression is a symbol expression.
+    pub fn is_symbol(&self) -> bool {
+        unsafe { mlirAffineExprIsASymbol(self.raw) }
+    }
+
+    /// Returns `true` if an expression is a constant expression.
+    pub fn is_constant(&self) -> bool {
+        unsafe { mlirAffineExprIsAConstant(self.raw) }
+    }
+
+    /// Returns `true` if an expression is a binary expression.
+    pub fn is_binary(&self) -> bool {
+        unsafe { mlirAffineExprIsABinary(self.raw) }
+    }
+
+    /// Returns `true` if an expression is an addition expression.
+    pub fn is_add(&self) -> bool {
+        unsafe { mlirAffineExprIsAAdd(self.raw) }
+    }
+
+    /// Returns `true` if an expression is a multiplication expression.
+    pub fn is_mul(&self) -> bool {
+        unsafe { mlirAffineExprIsAMul(self.raw) }
+    }
+
+    /// Returns `true` if an expression is a modulo expression.
+    pub fn is_mod(&self) -> bool {
+        unsafe { mlirAffineExprIsAMod(self.raw) }
+    }
+
+    /// Returns `true` if an expression is a floor division expression.
+    pub fn is_floor_div(&self) -> bool {
+        unsafe { mlirAffineExprIsAFloorDiv(self.raw) }
+    }
+
+    /// Returns `true` if an expression is a ceiling division expression.
+    pub fn is_ceil_div(&self) -> bool {
+        unsafe { mlirAffineExprIsACeilDiv(self.raw) }
+    }
+
+    /// Composes an expression with an affine map.
+    ///
+    /// Dimensions in the expression are replaced with results of the map.
+    pub fn compose(self, map: AffineMap<'c>) -> Self {
+        unsafe { Self::from_raw(mlirAffineExprCompose(self.raw, map.to_raw())) }
+    }
+
+    /// Replaces dimensions and symbols in an expression.
+    ///
+    /// Dimensions and symbols at positions not covered by replacements are
+    /// kept as they are.
+    pub fn replace_dimensions_and_symbols(self, dimensions: &[Self], symbols: &[Self]) -> Self {
+        if let Some(position) = self.position() {
+            let replacements = if self.is_dimension() {
+                dimensions
+            } else {
+                symbols
+            };
+
+            replacements.get(position).copied().unwrap_or(self)
+        } else if let (Some(lhs), Some(rhs)) = (self.lhs(), self.rhs()) {
+            let lhs = lhs.replace_dimensions_and_symbols(dimensions, symbols);
+            let rhs = rhs.replace_dimensions_and_symbols(dimensions, symbols);
+
+            if self.is_add() {
+                lhs + rhs
+            } else if self.is_mul() {
+                lhs * rhs
+            } else if self.is_mod() {
+                lhs % rhs
+            } else if self.is_floor_div() {
+                lhs.floor_div(rhs)
+            } else {
+                lhs.ceil_div(rhs)
+            }
+        } else {
+            self
+        }
+    }
+
+    /// Dumps an affine expression.
+    pub fn dump(&self) {
+        unsafe { mlirAffineExprDump(self.raw) }
+    }
+
+    /// Creates an affine expression from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirAffineExpr) -> Self {
+        Self {
+            raw,
+            _context: Default::default(),
+        }
+    }
+
+    /// Converts an affine expression into a raw object.
+    pub const fn to_raw(self) -> MlirAffineExpr {
+        self.raw
+    }
+}
+
+impl<'c> Add for AffineExpr<'c> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        unsafe { Self::from_raw(mlirAffineAddExprGet(self.raw, other.raw)) }
+    }
+}
+
+impl<'c> Sub for AffineExpr<'c> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + other * Self::constant(unsafe { self.context().to_ref() }, -1)
+    }
+}
+
+impl<'c> Mul for AffineExpr<'c> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        unsafe { Self::from_raw(mlirAffineMulExprGet(self.raw, other.raw)) }
+    }
+}
+
+impl<'c> Rem for AffineExpr<'c> {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        unsafe { Self::from_raw(mlirAffineModExprGet(self.raw, other.raw)) }
+    }
+}
+
+impl PartialEq for AffineExpr<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { mlirAffineExprEqual(self.raw, other.raw) }
+    }
+}
+
+impl Eq for AffineExpr<'_> {}
+
+impl Display for AffineExpr<'_> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        let mut data = (formatter, Ok(()));
+
+        unsafe {
+            mlirAffineExprPrint(
+                self.raw,
+                Some(print_callback),
+                &mut data as *mut _ as *mut c_void,
+            );
+        }
+
+        data.1
+    }
+}
+
+impl Debug for AffineExpr<'_> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn dimension() {
+        let context = create_test_context();
+        let expression = AffineExpr::dimension(&context, 1);
+
+        assert!(expression.is_dimension());
+        assert_eq!(expression.position(), Some(1));
+        assert_eq!(expression.to_string(), "d1");
+    }
+
+    #[test]
+    fn symbol() {
+        let context = create_test_context();
+        let expression = AffineExpr::symbol(&context, 2);
+
+        assert!(expression.is_symbol());
+        assert_eq!(expression.position(), Some(2));
+        assert_eq!(expression.to_string(), "s2");
+    }
+
+    #[test]
+    fn constant() {
+        let context = create_test_context();
+        let expression = AffineExpr::constant(&context, 42);
+
+        assert!(expression.is_constant());
+        assert_eq!(expression.value(), Some(42));
+        assert_eq!(expression.position(), None);
+        assert_eq!(expression.to_string(), "42");
+    }
+
+    #[test]
+    fn binary() {
+        let context = create_test_context();
+        let dimension = AffineExpr::dimension(&context, 0);
+        let symbol = AffineExpr::symbol(&context, 0);
+
+        let expression = dimension + symbol;
+
+        assert!(expression.is_add());
+        assert!(expression.is_binary());
+        assert_eq!(expression.lhs(), Some(dimension));
+        assert_eq!(expression.rhs(), Some(symbol));
+        assert_eq!(expression.to_string(), "d0 + s0");
+    }
+
+    #[test]
+    fn operators() {
+        let context = create_test_context();
+        let dimension = AffineExpr::dimension(&context, 0);
+        let symbol = AffineExpr::symbol(&context, 0);
+        let constant = AffineExpr::constant(&context, 4);
+
+        assert_eq!((dimension * constant).to_string(), "d0 * 4");
+        assert_eq!((dimension % constant).to_string(), "d0 mod 4");
+        assert_eq!((dimension - symbol).to_string(), "d0 - s0");
+        assert_eq!(dimension.floor_div(constant).to_string(), "d0 floordiv 4");
+        assert_eq!(dimension.ceil_div(constant).to_string(), "d0 ceildiv 4");
+    }
+
+    #[test]
+    fn constant_folding() {
+        let context = create_test_context();
+
+        assert_eq!(
+            AffineExpr::constant(&context, 2) * AffineExpr::constant(&context, 3),
+            AffineExpr::constant(&context, 6)
+        );
+    }
+
+    #[test]
+    fn predicates() {
+        let context = create_test_context();
+        let dimension = AffineExpr::dimension(&context, 0);
+        let symbol = AffineExpr::symbol(&context, 0);
+        let expression = dimension * AffineExpr::constant(&context, 4);
+
+        assert!(expression.is_pure_affine());
+        assert!(!(dimension * dimension).is_pure_affine());
+        assert!(symbol.is_symbolic_or_constant());
+        assert!(!dimension.is_symbolic_or_constant());
+        assert!(expression.is_multiple_of(2));
+        assert_eq!(expression.largest_known_divisor(), 4);
+        assert!(expression.is_function_of_dimension(0));
+        assert!(!expression.is_function_of_dimension(1));
+    }
+
+    #[test]
+    fn compose() {
+        let context = create_test_context();
+        let map = AffineMap::new(
+            &context,
+            1,
+            0,
+            &[AffineExpr::dimension(&context, 0) * AffineExpr::constant(&context, 2)],
+        );
+
+        assert_eq!(
+            (AffineExpr::dimension(&context, 0) + AffineExpr::constant(&context, 1))
+                .compose(map)
+                .to_string(),
+            "d0 * 2 + 1"
+        );
+    }
+
+    #[test]
+    fn replace_dimensions_and_symbols() {
+        let context = create_test_context();
+        let expression = AffineExpr::dimension(&context, 0) + AffineExpr::symbol(&context, 0);
+
+        assert_eq!(
+            expression
+                .replace_dimensions_and_symbols(
+                    &[AffineExpr::dimension(&context, 1)],
+                    &[AffineExpr::constant(&context, 3)],
+                )
+                .to_string(),
+            "d1 + 3"
+        );
+    }
+
+    #[test]
+    fn equal() {
+        let context = create_test_context();
+
+        assert_eq!(
+            AffineExpr::dimension(&context, 0),
+            AffineExpr::dimension(&context, 0)
+        );
+        assert_ne!(
+            AffineExpr::dimension(&context, 0),
+            AffineExpr::symbol(&context, 0)
+        );
+    }
+}
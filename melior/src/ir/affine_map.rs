@@ -1,10 +1,18 @@
+use super::AffineExpr;
 use crate::{
     context::{Context, ContextRef},
     utility::print_callback,
+    Error,
 };
 use mlir_sys::{
-    mlirAffineMapDump, mlirAffineMapEqual, mlirAffineMapGetContext, mlirAffineMapPrint,
-    MlirAffineMap,
+    mlirAffineMapConstantGet, mlirAffineMapDump, mlirAffineMapEmptyGet, mlirAffineMapEqual,
+    mlirAffineMapGet, mlirAffineMapGetContext, mlirAffineMapGetNumDims, mlirAffineMapGetNumInputs,
+    mlirAffineMapGetNumResults, mlirAffineMapGetNumSymbols, mlirAffineMapGetResult,
+    mlirAffineMapGetSingleConstantResult, mlirAffineMapGetSubMap, mlirAffineMapIsEmpty,
+    mlirAffineMapIsIdentity, mlirAffineMapIsMinorIdentity, mlirAffineMapIsPermutation,
+    mlirAffineMapIsProjectedPermutation, mlirAffineMapIsSingleConstant,
+    mlirAffineMapMinorIdentityGet, mlirAffineMapMultiDimIdentityGet, mlirAffineMapPermutationGet,
+    mlirAffineMapPrint, mlirAffineMapZeroResultGet, MlirAffineMap,
 };
 use std::{
     ffi::c_void,
@@ -20,11 +28,244 @@ pub struct AffineMap<'c> {
 }
 
 impl<'c> AffineMap<'c> {
+    /// Creates an affine map.
+    pub fn new(
+        context: &'c Context,
+        dimension_count: usize,
+        symbol_count: usize,
+        results: &[AffineExpr<'c>],
+    ) -> Self {
+        let mut results = results
+            .iter()
+            .map(|expression| expression.to_raw())
+            .collect::<Vec<_>>();
+
+        unsafe {
+            Self::from_raw(mlirAffineMapGet(
+                context.to_raw(),
+                dimension_count as isize,
+                symbol_count as isize,
+                results.len() as isize,
+                results.as_mut_ptr(),
+            ))
+        }
+    }
+
+    /// Creates an empty affine map.
+    pub fn empty(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirAffineMapEmptyGet(context.to_raw())) }
+    }
+
+    /// Creates an affine map with no results.
+    pub fn zero_result(context: &'c Context, dimension_count: usize, symbol_count: usize) -> Self {
+        unsafe {
+            Self::from_raw(mlirAffineMapZeroResultGet(
+                context.to_raw(),
+                dimension_count as isize,
+                symbol_count as isize,
+            ))
+        }
+    }
+
+    /// Creates a single constant result affine map.
+    pub fn constant(context: &'c Context, value: i64) -> Self {
+        unsafe { Self::from_raw(mlirAffineMapConstantGet(context.to_raw(), value)) }
+    }
+
+    /// Creates an identity affine map.
+    pub fn identity(context: &'c Context, dimension_count: usize) -> Self {
+        unsafe {
+            Self::from_raw(mlirAffineMapMultiDimIdentityGet(
+                context.to_raw(),
+                dimension_count as isize,
+            ))
+        }
+    }
+
+    /// Creates a minor identity affine map whose results are the trailing
+    /// dimensions.
+    ///
+    /// It returns `None` if the result count exceeds the dimension count.
+    pub fn minor_identity(
+        context: &'c Context,
+        dimension_count: usize,
+        result_count: usize,
+    ) -> Option<Self> {
+        (result_count <= dimension_count).then(|| unsafe {
+            Self::from_raw(mlirAffineMapMinorIdentityGet(
+                context.to_raw(),
+                dimension_count as isize,
+                result_count as isize,
+            ))
+        })
+    }
+
+    /// Creates a permutation affine map.
+    ///
+    /// It returns `None` if the given positions are not a permutation.
+    pub fn permutation(context: &'c Context, permutation: &[usize]) -> Option<Self> {
+        let mut sorted = permutation.to_vec();
+        sorted.sort_unstable();
+
+        if sorted.into_iter().ne(0..permutation.len()) {
+            return None;
+        }
+
+        let mut permutation = permutation
+            .iter()
+            .map(|&position| position as u32)
+            .collect::<Vec<_>>();
+
+        Some(unsafe {
+            Self::from_raw(mlirAffineMapPermutationGet(
+                context.to_raw(),
+                permutation.len() as isize,
+                permutation.as_mut_ptr(),
+            ))
+        })
+    }
+
     /// Returns a context.
     pub fn context(&self) -> ContextRef<'c> {
         unsafe { ContextRef::from_raw(mlirAffineMapGetContext(self.raw)) }
     }
 
+    /// Returns a number of dimensions.
+    pub fn dimension_count(&self) -> usize {
+        unsafe { mlirAffineMapGetNumDims(self.raw) as usize }
+    }
+
+    /// Returns a number of symbols.
+    pub fn symbol_count(&self) -> usize {
+        unsafe { mlirAffineMapGetNumSymbols(self.raw) as usize }
+    }
+
+    /// Returns a number of inputs, which are dimensions and symbols.
+    pub fn input_count(&self) -> usize {
+        unsafe { mlirAffineMapGetNumInputs(self.raw) as usize }
+    }
+
+    /// Returns a number of results.
+    pub fn result_count(&self) -> usize {
+        unsafe { mlirAffineMapGetNumResults(self.raw) as usize }
+    }
+
+    /// Returns a result at a position.
+    pub fn result(&self, index: usize) -> Option<AffineExpr<'c>> {
+        (index < self.result_count()).then(|| unsafe {
+            AffineExpr::from_raw(mlirAffineMapGetResult(self.raw, index as isize))
+        })
+    }
+
+    /// Returns results.
+    pub fn results(&self) -> impl Iterator<Item = AffineExpr<'c>> + '_ {
+        (0..self.result_count()).map(|index| unsafe {
+            AffineExpr::from_raw(mlirAffineMapGetResult(self.raw, index as isize))
+        })
+    }
+
+    /// Returns a constant result of a single constant result affine map.
+    pub fn single_constant_result(&self) -> Option<i64> {
+        self.is_single_constant()
+            .then(|| unsafe { mlirAffineMapGetSingleConstantResult(self.raw) })
+    }
+
+    /// Returns an affine map with only results at given positions.
+    ///
+    /// It returns `None` if any of the positions is out of bounds.
+    pub fn sub_map(&self, positions: &[usize]) -> Option<Self> {
+        if positions
+            .iter()
+            .any(|&position| position >= self.result_count())
+        {
+            return None;
+        }
+
+        let mut positions = positions
+            .iter()
+            .map(|&position| position as isize)
+            .collect::<Vec<_>>();
+
+        Some(unsafe {
+            Self::from_raw(mlirAffineMapGetSubMap(
+                self.raw,
+                positions.len() as isize,
+                positions.as_mut_ptr(),
+            ))
+        })
+    }
+
+    /// Composes an affine map with another.
+    ///
+    /// The resulting map takes inputs of the other map and applies this map to
+    /// its results. Symbols of the other map are appended after ones of this
+    /// map.
+    ///
+    /// It returns an error if the number of dimensions of this map does not
+    /// match the number of results of the other map.
+    pub fn compose(self, other: Self) -> Result<Self, Error> {
+        if self.dimension_count() != other.result_count() {
+            return Err(Error::DimensionCountMismatch {
+                expected: self.dimension_count(),
+                actual: other.result_count(),
+            });
+        }
+
+        let context = unsafe { self.context().to_ref() };
+        let symbol_count = self.symbol_count();
+
+        let dimensions = (0..other.dimension_count())
+            .map(|position| AffineExpr::dimension(context, position))
+            .collect::<Vec<_>>();
+        let symbols = (0..other.symbol_count())
+            .map(|position| AffineExpr::symbol(context, symbol_count + position))
+            .collect::<Vec<_>>();
+        let other_results = other
+            .results()
+            .map(|expression| expression.replace_dimensions_and_symbols(&dimensions, &symbols))
+            .collect::<Vec<_>>();
+
+        Ok(Self::new(
+            context,
+            other.dimension_count(),
+            symbol_count + other.symbol_count(),
+            &self
+                .results()
+                .map(|expression| expression.replace_dimensions_and_symbols(&other_results, &[]))
+                .collect::<Vec<_>>(),
+        ))
+    }
+
+    /// Returns `true` if an affine map is empty.
+    pub fn is_empty(&self) -> bool {
+        unsafe { mlirAffineMapIsEmpty(self.raw) }
+    }
+
+    /// Returns `true` if an affine map is an identity.
+    pub fn is_identity(&self) -> bool {
+        unsafe { mlirAffineMapIsIdentity(self.raw) }
+    }
+
+    /// Returns `true` if an affine map is a minor identity.
+    pub fn is_minor_identity(&self) -> bool {
+        unsafe { mlirAffineMapIsMinorIdentity(self.raw) }
+    }
+
+    /// Returns `true` if an affine map is a permutation.
+    pub fn is_permutation(&self) -> bool {
+        unsafe { mlirAffineMapIsPermutation(self.raw) }
+    }
+
+    /// Returns `true` if an affine map is a projected permutation.
+    pub fn is_projected_permutation(&self) -> bool {
+        unsafe { mlirAffineMapIsProjectedPermutation(self.raw) }
+    }
+
+    /// Returns `true` if an affine map has a single constant result.
+    pub fn is_single_constant(&self) -> bool {
+        unsafe { mlirAffineMapIsSingleConstant(self.raw) }
+    }
+
     /// Dumps an affine map.
     pub fn dump(&self) {
         unsafe { mlirAffineMapDump(self.raw) }
@@ -41,6 +282,11 @@ impl<'c> AffineMap<'c> {
             _context: Default::default(),
         }
     }
+
+    /// Converts an affine map into a raw object.
+    pub const fn to_raw(self) -> MlirAffineMap {
+        self.raw
+    }
 }
 
 impl PartialEq for AffineMap<'_> {
@@ -72,3 +318,185 @@ impl Debug for AffineMap<'_> {
         Display::fmt(self, formatter)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+        let map = AffineMap::new(
+            &context,
+            2,
+            1,
+            &[
+                AffineExpr::dimension(&context, 1),
+                AffineExpr::dimension(&context, 0) + AffineExpr::symbol(&context, 0),
+            ],
+        );
+
+        assert_eq!(map.to_string(), "(d0, d1)[s0] -> (d1, d0 + s0)");
+        assert_eq!(map.dimension_count(), 2);
+        assert_eq!(map.symbol_count(), 1);
+        assert_eq!(map.input_count(), 3);
+        assert_eq!(map.result_count(), 2);
+        assert_eq!(map.result(0), Some(AffineExpr::dimension(&context, 1)));
+        assert_eq!(map.result(2), None);
+        assert_eq!(map.results().count(), 2);
+    }
+
+    #[test]
+    fn empty() {
+        let context = create_test_context();
+        let map = AffineMap::empty(&context);
+
+        assert!(map.is_empty());
+        assert_eq!(map.to_string(), "() -> ()");
+    }
+
+    #[test]
+    fn zero_result() {
+        let context = create_test_context();
+
+        assert_eq!(
+            AffineMap::zero_result(&context, 2, 1).to_string(),
+            "(d0, d1)[s0] -> ()"
+        );
+    }
+
+    #[test]
+    fn constant() {
+        let context = create_test_context();
+        let map = AffineMap::constant(&context, 42);
+
+        assert!(map.is_single_constant());
+        assert_eq!(map.single_constant_result(), Some(42));
+        assert_eq!(
+            AffineMap::identity(&context, 1).single_constant_result(),
+            None
+        );
+    }
+
+    #[test]
+    fn identity() {
+        let context = create_test_context();
+        let map = AffineMap::identity(&context, 3);
+
+        assert!(map.is_identity());
+        assert_eq!(map.to_string(), "(d0, d1, d2) -> (d0, d1, d2)");
+    }
+
+    #[test]
+    fn minor_identity() {
+        let context = create_test_context();
+        let map = AffineMap::minor_identity(&context, 3, 2).unwrap();
+
+        assert!(map.is_minor_identity());
+        assert_eq!(map.to_string(), "(d0, d1, d2) -> (d1, d2)");
+        assert_eq!(AffineMap::minor_identity(&context, 2, 3), None);
+    }
+
+    #[test]
+    fn permutation() {
+        let context = create_test_context();
+        let map = AffineMap::permutation(&context, &[1, 2, 0]).unwrap();
+
+        assert!(map.is_permutation());
+        assert!(map.is_projected_permutation());
+        assert_eq!(map.to_string(), "(d0, d1, d2) -> (d1, d2, d0)");
+    }
+
+    #[test]
+    fn permutation_invalid() {
+        let context = create_test_context();
+
+        assert_eq!(AffineMap::permutation(&context, &[0, 0]), None);
+        assert_eq!(AffineMap::permutation(&context, &[1, 2]), None);
+    }
+
+    #[test]
+    fn projected_permutation() {
+        let context = create_test_context();
+        let map = AffineMap::new(
+            &context,
+            3,
+            0,
+            &[
+                AffineExpr::dimension(&context, 2),
+                AffineExpr::dimension(&context, 0),
+            ],
+        );
+
+        assert!(map.is_projected_permutation());
+        assert!(!map.is_permutation());
+    }
+
+    #[test]
+    fn sub_map() {
+        let context = create_test_context();
+        let map = AffineMap::identity(&context, 3);
+
+        assert_eq!(
+            map.sub_map(&[2, 0]).unwrap().to_string(),
+            "(d0, d1, d2) -> (d2, d0)"
+        );
+        assert_eq!(map.sub_map(&[3]), None);
+    }
+
+    #[test]
+    fn compose() {
+        let context = create_test_context();
+        let map = AffineMap::new(
+            &context,
+            2,
+            1,
+            &[AffineExpr::dimension(&context, 0) + AffineExpr::symbol(&context, 0)],
+        );
+        let other = AffineMap::new(
+            &context,
+            1,
+            1,
+            &[
+                AffineExpr::dimension(&context, 0) * AffineExpr::symbol(&context, 0),
+                AffineExpr::dimension(&context, 0),
+            ],
+        );
+
+        assert_eq!(
+            map.compose(other).unwrap().to_string(),
+            "(d0)[s0, s1] -> (d0 * s1 + s0)"
+        );
+    }
+
+    #[test]
+    fn compose_dimension_count_mismatch() {
+        let context = create_test_context();
+        let map = AffineMap::identity(&context, 2);
+        let other = AffineMap::identity(&context, 3);
+
+        assert_eq!(
+            map.compose(other),
+            Err(Error::DimensionCountMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn equal() {
+        let context = create_test_context();
+
+        assert_eq!(
+            AffineMap::identity(&context, 2),
+            AffineMap::permutation(&context, &[0, 1]).unwrap()
+        );
+        assert_ne!(
+            AffineMap::identity(&context, 2),
+            AffineMap::permutation(&context, &[1, 0]).unwrap()
+        );
+    }
+}
@@ -2,26 +2,60 @@
 
 #[macro_use]
 mod r#macro;
+mod affine_map;
 mod array;
+mod attribute_class;
 mod attribute_like;
 mod bool;
+mod dense_bool_array;
 mod dense_elements;
+mod dense_f32_array;
+mod dense_f64_array;
 mod dense_i32_array;
 mod dense_i64_array;
+mod dense_resource_elements;
+mod dictionary;
 mod flat_symbol_ref;
 mod float;
 mod integer;
+mod integer_set;
+mod location;
+mod opaque;
+mod sparse_elements;
+mod strided_layout;
 mod string;
+mod symbol_ref;
 mod r#type;
+mod unit;
 
 pub use self::{
-    array::ArrayAttribute, attribute_like::AttributeLike, bool::BoolAttribute,
-    dense_elements::DenseElementsAttribute, dense_i32_array::DenseI32ArrayAttribute,
-    dense_i64_array::DenseI64ArrayAttribute, flat_symbol_ref::FlatSymbolRefAttribute,
-    float::FloatAttribute, integer::IntegerAttribute, r#type::TypeAttribute,
+    affine_map::AffineMapAttribute,
+    array::ArrayAttribute,
+    attribute_class::AttributeClass,
+    attribute_like::AttributeLike,
+    bool::BoolAttribute,
+    dense_bool_array::DenseBoolArrayAttribute,
+    dense_elements::{DenseElement, DenseElementsAttribute, DenseResourceElement},
+    dense_f32_array::DenseF32ArrayAttribute,
+    dense_f64_array::DenseF64ArrayAttribute,
+    dense_i32_array::DenseI32ArrayAttribute,
+    dense_i64_array::DenseI64ArrayAttribute,
+    dense_resource_elements::DenseResourceElementsAttribute,
+    dictionary::DictionaryAttribute,
+    flat_symbol_ref::FlatSymbolRefAttribute,
+    float::FloatAttribute,
+    integer::IntegerAttribute,
+    integer_set::IntegerSetAttribute,
+    location::LocationAttribute,
+    opaque::OpaqueAttribute,
+    r#type::TypeAttribute,
+    sparse_elements::SparseElementsAttribute,
+    strided_layout::StridedLayoutAttribute,
     string::StringAttribute,
+    symbol_ref::SymbolRefAttribute,
+    unit::UnitAttribute,
 };
-use crate::{context::Context, string_ref::StringRef, utility::print_callback};
+use crate::{context::Context, string_ref::StringRef, utility::print_callback, Error};
 use mlir_sys::{
     mlirAttributeEqual, mlirAttributeGetNull, mlirAttributeParseGet, mlirAttributePrint,
     mlirUnitAttrGet, MlirAttribute,
@@ -51,6 +85,16 @@ impl<'c> Attribute<'c> {
         }
     }
 
+    /// Parses an attribute and returns diagnostics on failure.
+    pub fn parse_with_diagnostics(context: &'c Context, source: &str) -> Result<Self, Error> {
+        let capture = context.capture_diagnostics();
+
+        Self::parse(context, source).ok_or_else(|| Error::Parse {
+            r#type: "attribute",
+            diagnostics: capture.finish(),
+        })
+    }
+
     /// Creates a unit attribute.
     pub fn unit(context: &'c Context) -> Self {
         unsafe { Self::from_raw(mlirUnitAttrGet(context.to_raw())) }
@@ -124,16 +168,29 @@ impl Debug for Attribute<'_> {
 
 from_subtypes!(
     Attribute,
+    AffineMapAttribute,
     ArrayAttribute,
     BoolAttribute,
+    DenseBoolArrayAttribute,
     DenseElementsAttribute,
+    DenseF32ArrayAttribute,
+    DenseF64ArrayAttribute,
     DenseI32ArrayAttribute,
     DenseI64ArrayAttribute,
+    DenseResourceElementsAttribute,
+    DictionaryAttribute,
     FlatSymbolRefAttribute,
     FloatAttribute,
     IntegerAttribute,
+    IntegerSetAttribute,
+    LocationAttribute,
+    OpaqueAttribute,
+    SparseElementsAttribute,
+    StridedLayoutAttribute,
     StringAttribute,
+    SymbolRefAttribute,
     TypeAttribute,
+    UnitAttribute,
 );
 
 #[cfg(test)]
@@ -142,6 +199,7 @@ mod tests {
     use crate::{
         ir::{Type, TypeLike},
         test::create_test_context,
+        Error,
     };
 
     #[test]
@@ -161,6 +219,29 @@ mod tests {
         assert!(Attribute::parse(&Context::new(), "z").is_none());
     }
 
+    #[test]
+    fn parse_with_diagnostics() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Attribute::parse_with_diagnostics(&context, "unit"),
+            Ok(Attribute::unit(&context))
+        );
+
+        let Err(Error::Parse {
+            r#type: "attribute",
+            diagnostics,
+        }) = Attribute::parse_with_diagnostics(&context, "z")
+        else {
+            panic!("attribute parse expected to fail");
+        };
+
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0]
+            .message()
+            .contains("expected attribute value"));
+    }
+
     #[test]
     fn context() {
         let context = create_test_context();
@@ -297,6 +378,43 @@ mod tests {
         assert!(Attribute::parse(&context, "@foo").unwrap().is_symbol_ref());
     }
 
+    #[test]
+    fn isa() {
+        let context = create_test_context();
+        let attribute = Attribute::parse(&context, "42").unwrap();
+
+        assert!(attribute.isa::<Attribute>());
+        assert!(attribute.isa::<IntegerAttribute>());
+        assert!(!attribute.isa::<StringAttribute>());
+    }
+
+    #[test]
+    fn cast() {
+        let context = create_test_context();
+        let attribute = Attribute::parse(&context, "42").unwrap();
+
+        assert_eq!(attribute.cast::<IntegerAttribute>().unwrap().value(), 42);
+        assert_eq!(attribute.cast::<Attribute>(), Ok(attribute));
+        assert_eq!(
+            attribute.cast::<StringAttribute>().unwrap_err(),
+            Error::AttributeExpected("string", "42 : i64".into())
+        );
+    }
+
+    #[test]
+    fn dyn_cast() {
+        let context = create_test_context();
+        let attribute = Attribute::parse(&context, "42").unwrap();
+
+        assert_eq!(
+            attribute
+                .dyn_cast::<IntegerAttribute>()
+                .map(|attribute| attribute.value()),
+            Some(42)
+        );
+        assert!(attribute.dyn_cast::<StringAttribute>().is_none());
+    }
+
     #[test]
     fn equal() {
         let context = create_test_context();
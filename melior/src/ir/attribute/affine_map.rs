@@ -0,0 +1,48 @@
+use super::{Attribute, AttributeLike};
+use crate::{ir::AffineMap, Error};
+use mlir_sys::{mlirAffineMapAttrGet, mlirAffineMapAttrGetValue, MlirAttribute};
+
+/// An affine map attribute.
+#[derive(Clone, Copy)]
+pub struct AffineMapAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> AffineMapAttribute<'c> {
+    /// Creates an affine map attribute.
+    pub fn new(map: AffineMap<'c>) -> Self {
+        unsafe { Self::from_raw(mlirAffineMapAttrGet(map.to_raw())) }
+    }
+
+    /// Returns an affine map value.
+    pub fn value(&self) -> AffineMap<'c> {
+        unsafe { AffineMap::from_raw(mlirAffineMapAttrGetValue(self.to_raw())) }
+    }
+}
+
+attribute_traits!(AffineMapAttribute, is_affine_map, "affine map");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+
+    #[test]
+    fn value() {
+        let context = create_test_context();
+        let map = AffineMap::identity(&context, 2);
+
+        assert_eq!(AffineMapAttribute::new(map).value(), map);
+    }
+
+    #[test]
+    fn try_from() {
+        let context = create_test_context();
+        let attribute = Attribute::parse(&context, "affine_map<(d0, d1) -> (d1, d0)>").unwrap();
+
+        assert_eq!(
+            AffineMapAttribute::try_from(attribute).unwrap().value(),
+            AffineMap::permutation(&context, &[1, 0]).unwrap()
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use super::Attribute;
+
+/// A trait for attribute classes.
+///
+/// It checks if attributes are instances of an attribute class without
+/// converting them.
+pub trait AttributeClass<'c>: TryFrom<Attribute<'c>> {
+    /// Returns `true` if an attribute is an instance of an attribute class.
+    fn is_instance(attribute: Attribute<'c>) -> bool;
+}
+
+impl<'c> AttributeClass<'c> for Attribute<'c> {
+    fn is_instance(_: Attribute<'c>) -> bool {
+        true
+    }
+}
@@ -1,6 +1,7 @@
+use super::{Attribute, AttributeClass};
 use crate::{
     ir::{r#type::TypeId, Type},
-    ContextRef,
+    ContextRef, Error,
 };
 use melior_macro::attribute_check_functions;
 use mlir_sys::{
@@ -28,6 +29,32 @@ pub trait AttributeLike<'c> {
         unsafe { TypeId::from_raw(mlirAttributeGetTypeID(self.to_raw())) }
     }
 
+    /// Returns `true` if an attribute is an instance of a given attribute
+    /// class.
+    fn isa<T: AttributeClass<'c>>(&self) -> bool {
+        T::is_instance(unsafe { Attribute::from_raw(self.to_raw()) })
+    }
+
+    /// Casts an attribute into a given attribute class.
+    fn cast<T: TryFrom<Attribute<'c>>>(&self) -> Result<T, Error>
+    where
+        Error: From<T::Error>,
+    {
+        Ok(T::try_from(unsafe { Attribute::from_raw(self.to_raw()) })?)
+    }
+
+    /// Casts an attribute into a given attribute class if it is an instance of
+    /// it.
+    fn dyn_cast<T: AttributeClass<'c>>(&self) -> Option<T> {
+        let attribute = unsafe { Attribute::from_raw(self.to_raw()) };
+
+        if T::is_instance(attribute) {
+            T::try_from(attribute).ok()
+        } else {
+            None
+        }
+    }
+
     /// Dumps a attribute.
     fn dump(&self) {
         unsafe { mlirAttributeDump(self.to_raw()) }
@@ -0,0 +1,88 @@
+use super::{Attribute, AttributeLike};
+use crate::{Context, Error};
+use mlir_sys::{
+    mlirDenseArrayGetNumElements, mlirDenseBoolArrayGet, mlirDenseBoolArrayGetElement,
+    MlirAttribute,
+};
+
+/// A dense bool array attribute.
+#[derive(Clone, Copy)]
+pub struct DenseBoolArrayAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> DenseBoolArrayAttribute<'c> {
+    /// Creates a dense bool array attribute.
+    pub fn new(context: &'c Context, values: &[bool]) -> Self {
+        let values = values
+            .iter()
+            .map(|&value| i32::from(value))
+            .collect::<Vec<_>>();
+
+        unsafe {
+            Self::from_raw(mlirDenseBoolArrayGet(
+                context.to_raw(),
+                values.len() as isize,
+                values.as_ptr(),
+            ))
+        }
+    }
+
+    /// Returns a length.
+    pub fn len(&self) -> usize {
+        (unsafe { mlirDenseArrayGetNumElements(self.attribute.to_raw()) }) as usize
+    }
+
+    /// Checks if an array is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an element.
+    pub fn element(&self, index: usize) -> Result<bool, Error> {
+        if index < self.len() {
+            Ok(unsafe { mlirDenseBoolArrayGetElement(self.attribute.to_raw(), index as isize) })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "array element",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+}
+
+attribute_traits!(
+    DenseBoolArrayAttribute,
+    is_dense_bool_array,
+    "dense bool array"
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::test::create_test_context;
+
+    use super::*;
+
+    #[test]
+    fn element() {
+        let context = create_test_context();
+        let attribute = DenseBoolArrayAttribute::new(&context, &[true, false, true]);
+
+        assert!(attribute.element(0).unwrap());
+        assert!(!attribute.element(1).unwrap());
+        assert!(attribute.element(2).unwrap());
+        assert!(matches!(
+            attribute.element(3),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn len() {
+        let context = create_test_context();
+        let attribute = DenseBoolArrayAttribute::new(&context, &[true, false, true]);
+
+        assert_eq!(attribute.len(), 3);
+    }
+}
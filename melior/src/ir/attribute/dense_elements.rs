@@ -1,12 +1,22 @@
+mod element;
+
+pub use self::element::{DenseElement, DenseResourceElement};
 use super::{Attribute, AttributeLike};
 use crate::{
-    ir::{Type, TypeLike},
+    ir::{
+        r#type::{FloatType, IntegerType},
+        Type, TypeLike,
+    },
     Error,
 };
 use mlir_sys::{
     mlirDenseElementsAttrGet, mlirDenseElementsAttrGetInt32Value,
-    mlirDenseElementsAttrGetInt64Value, mlirElementsAttrGetNumElements, MlirAttribute,
+    mlirDenseElementsAttrGetInt64Value, mlirDenseElementsAttrGetRawData,
+    mlirDenseElementsAttrIsSplat, mlirDenseElementsAttrRawBufferGet,
+    mlirElementsAttrGetNumElements, mlirShapedTypeGetDimSize, mlirShapedTypeGetElementType,
+    mlirShapedTypeGetRank, mlirShapedTypeHasStaticShape, MlirAttribute,
 };
+use std::slice;
 
 /// A dense elements attribute.
 #[derive(Clone, Copy)]
@@ -30,6 +40,123 @@ impl<'c> DenseElementsAttribute<'c> {
         }
     }
 
+    /// Creates a dense elements attribute from a slice of values.
+    ///
+    /// The number of values must match the number of elements of a statically
+    /// shaped type.
+    pub fn from_slice<T: DenseElement>(r#type: Type<'c>, values: &[T]) -> Result<Self, Error> {
+        let count = Self::check_type::<T>(r#type)?;
+
+        if values.len() == count {
+            Ok(unsafe { Self::from_raw(T::create(r#type.to_raw(), values)) })
+        } else {
+            Err(Error::ElementCountMismatch {
+                expected: count,
+                actual: values.len(),
+            })
+        }
+    }
+
+    /// Creates a dense elements attribute with all elements of the same value.
+    pub fn splat<T: DenseElement>(r#type: Type<'c>, value: T) -> Result<Self, Error> {
+        Self::check_type::<T>(r#type)?;
+
+        Ok(unsafe { Self::from_raw(T::splat(r#type.to_raw(), value)) })
+    }
+
+    /// Creates a dense elements attribute from a raw buffer.
+    ///
+    /// A buffer must contain all elements of a statically shaped type, or a
+    /// single element for a splat, in the native byte order. Boolean elements
+    /// are packed into bits.
+    pub fn from_raw_buffer(r#type: Type<'c>, data: &[u8]) -> Result<Self, Error> {
+        check_static_shape(r#type)?;
+
+        unsafe {
+            Attribute::from_option_raw(mlirDenseElementsAttrRawBufferGet(
+                r#type.to_raw(),
+                data.len(),
+                data.as_ptr() as *const _,
+            ))
+        }
+        .map(|attribute| unsafe { Self::from_raw(attribute.to_raw()) })
+        .ok_or_else(|| Error::InvalidRawBuffer(r#type.to_string()))
+    }
+
+    /// Creates a dense elements attribute of an integer element type from
+    /// little-endian 64-bit words of two's complement integers.
+    ///
+    /// Each element consists of as many words as the width of the element type
+    /// needs. Bits beyond the width are ignored.
+    pub fn from_words(r#type: Type<'c>, words: &[u64]) -> Result<Self, Error> {
+        check_static_shape(r#type)?;
+
+        let width = integer_element_width(r#type)?;
+        let word_count = width.div_ceil(64);
+        let expected = static_element_count(r#type) * word_count;
+
+        if words.len() != expected {
+            return Err(Error::ElementCountMismatch {
+                expected,
+                actual: words.len(),
+            });
+        }
+
+        let byte_count = width.div_ceil(8);
+        let mut data = Vec::with_capacity(expected / word_count * byte_count);
+
+        for element in words.chunks(word_count) {
+            data.extend(
+                element
+                    .iter()
+                    .flat_map(|word| word.to_le_bytes())
+                    .take(byte_count),
+            );
+
+            if width % 8 != 0 {
+                if let Some(byte) = data.last_mut() {
+                    *byte &= (1 << (width % 8)) - 1;
+                }
+            }
+        }
+
+        Self::from_raw_buffer(r#type, &data)
+    }
+
+    /// Creates a dense elements attribute of a float element type from bit
+    /// patterns.
+    ///
+    /// Bits beyond the width of the element type are ignored. Float types wider
+    /// than 64 bits are not supported.
+    pub fn from_float_bits(r#type: Type<'c>, bits: &[u64]) -> Result<Self, Error> {
+        check_static_shape(r#type)?;
+
+        let width = float_element_width(r#type)?;
+        let count = static_element_count(r#type);
+
+        if bits.len() != count {
+            return Err(Error::ElementCountMismatch {
+                expected: count,
+                actual: bits.len(),
+            });
+        }
+
+        let mask = u64::MAX >> (u64::BITS as usize - width);
+
+        Self::from_raw_buffer(
+            r#type,
+            &bits
+                .iter()
+                .flat_map(|bits| {
+                    (bits & mask)
+                        .to_le_bytes()
+                        .into_iter()
+                        .take(width.div_ceil(8))
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+
     /// Returns a length.
     pub fn len(&self) -> usize {
         (unsafe { mlirElementsAttrGetNumElements(self.attribute.to_raw()) }) as usize
@@ -40,6 +167,113 @@ impl<'c> DenseElementsAttribute<'c> {
         self.len() == 0
     }
 
+    /// Returns `true` if all elements have the same value.
+    pub fn is_splat(&self) -> bool {
+        unsafe { mlirDenseElementsAttrIsSplat(self.to_raw()) }
+    }
+
+    /// Returns an element.
+    pub fn element<T: DenseElement>(&self, index: usize) -> Result<T, Error> {
+        self.check_element_type::<T>()?;
+
+        if index < self.len() {
+            Ok(unsafe { T::element(self.to_raw(), index) })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "dense element",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns all elements.
+    pub fn elements<T: DenseElement>(&self) -> Result<impl Iterator<Item = T> + '_, Error> {
+        self.check_element_type::<T>()?;
+
+        Ok((0..self.len()).map(|index| unsafe { T::element(self.to_raw(), index) }))
+    }
+
+    /// Returns little-endian 64-bit words of an integer element.
+    pub fn element_words(&self, index: usize) -> Result<Vec<u64>, Error> {
+        let width = integer_element_width(self.r#type())?;
+
+        Ok(self
+            .raw_element(index, width.div_ceil(8))?
+            .chunks(8)
+            .map(|chunk| {
+                let mut bytes = [0; 8];
+                bytes[..chunk.len()].copy_from_slice(chunk);
+                u64::from_le_bytes(bytes)
+            })
+            .collect())
+    }
+
+    /// Returns a bit pattern of a float element.
+    pub fn float_element_bits(&self, index: usize) -> Result<u64, Error> {
+        let width = float_element_width(self.r#type())?;
+        let data = self.raw_element(index, width.div_ceil(8))?;
+        let mut bytes = [0; 8];
+        bytes[..data.len()].copy_from_slice(data);
+
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    fn raw_element(&self, index: usize, byte_count: usize) -> Result<&[u8], Error> {
+        if index >= self.len() {
+            return Err(Error::PositionOutOfBounds {
+                name: "dense element",
+                value: self.to_string(),
+                index,
+            });
+        }
+
+        let index = if self.is_splat() { 0 } else { index };
+
+        Ok(unsafe {
+            slice::from_raw_parts(
+                (mlirDenseElementsAttrGetRawData(self.to_raw()) as *const u8)
+                    .add(index * byte_count),
+                byte_count,
+            )
+        })
+    }
+
+    /// Converts elements into a vector.
+    pub fn to_vec<T: DenseElement>(&self) -> Result<Vec<T>, Error> {
+        Ok(self.elements()?.collect())
+    }
+
+    fn check_element_type<T: DenseElement>(&self) -> Result<(), Error> {
+        if T::is_element_type(unsafe {
+            Type::from_raw(mlirShapedTypeGetElementType(self.r#type().to_raw()))
+        }) {
+            Ok(())
+        } else {
+            Err(Error::ElementExpected {
+                r#type: T::NAME,
+                value: self.to_string(),
+            })
+        }
+    }
+
+    // Checks if a type is a statically shaped type of a valid element type and
+    // returns its number of elements.
+    pub(super) fn check_type<T: DenseElement>(r#type: Type<'c>) -> Result<usize, Error> {
+        check_static_shape(r#type)?;
+
+        if !T::is_element_type(unsafe {
+            Type::from_raw(mlirShapedTypeGetElementType(r#type.to_raw()))
+        }) {
+            return Err(Error::ElementExpected {
+                r#type: T::NAME,
+                value: r#type.to_string(),
+            });
+        }
+
+        Ok(static_element_count(r#type))
+    }
+
     /// Returns an i32 element.
     // TODO Prevent calling these type specific methods on other types.
     pub fn i32_element(&self, index: usize) -> Result<i32, Error> {
@@ -82,6 +316,48 @@ impl<'c> DenseElementsAttribute<'c> {
     }
 }
 
+fn check_static_shape(r#type: Type) -> Result<(), Error> {
+    if r#type.is_shaped() && unsafe { mlirShapedTypeHasStaticShape(r#type.to_raw()) } {
+        Ok(())
+    } else {
+        Err(Error::TypeExpected("statically shaped", r#type.to_string()))
+    }
+}
+
+fn static_element_count(r#type: Type) -> usize {
+    (0..unsafe { mlirShapedTypeGetRank(r#type.to_raw()) } as isize)
+        .map(|index| unsafe { mlirShapedTypeGetDimSize(r#type.to_raw(), index) } as usize)
+        .product()
+}
+
+// Returns a width of an integer element type. Boolean elements are excluded as
+// they are packed into bits.
+fn integer_element_width(r#type: Type) -> Result<usize, Error> {
+    let element_type = unsafe { Type::from_raw(mlirShapedTypeGetElementType(r#type.to_raw())) };
+
+    match IntegerType::try_from(element_type) {
+        _ if element_type.is_index() => Ok(64),
+        Ok(element_type) if element_type.width() > 1 => Ok(element_type.width() as usize),
+        _ => Err(Error::ElementExpected {
+            r#type: "multi-bit integer",
+            value: r#type.to_string(),
+        }),
+    }
+}
+
+// Returns a width of a float element type.
+fn float_element_width(r#type: Type) -> Result<usize, Error> {
+    let element_type = unsafe { Type::from_raw(mlirShapedTypeGetElementType(r#type.to_raw())) };
+
+    match FloatType::try_from(element_type) {
+        Ok(element_type) if element_type.width() <= u64::BITS => Ok(element_type.width() as usize),
+        _ => Err(Error::ElementExpected {
+            r#type: "float of at most 64 bits",
+            value: r#type.to_string(),
+        }),
+    }
+}
+
 attribute_traits!(DenseElementsAttribute, is_dense_elements, "dense elements");
 
 #[cfg(test)]
@@ -90,10 +366,11 @@ mod tests {
     use crate::{
         ir::{
             attribute::IntegerAttribute,
-            r#type::{IntegerType, MemRefType},
+            r#type::{FloatType, IntegerType, MemRefType, RankedTensorType},
         },
         test::create_test_context,
     };
+    use pretty_assertions::assert_eq;
 
     #[test]
     fn i32_element() {
@@ -153,4 +430,434 @@ mod tests {
 
         assert_eq!(attribute.len(), 3);
     }
+
+    #[test]
+    fn from_slice() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::from_slice(
+            RankedTensorType::new(&[2, 2], IntegerType::new(&context, 32).into(), None).into(),
+            &[1i32, 2, 3, 4],
+        )
+        .unwrap();
+
+        assert_eq!(
+            attribute.to_string(),
+            "dense<[[1, 2], [3, 4]]> : tensor<2x2xi32>"
+        );
+        assert_eq!(attribute.to_vec::<i32>(), Ok(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn from_slice_integers() {
+        let context = create_test_context();
+
+        macro_rules! assert_integers {
+            ($type:ty, $bits:expr, $integer_type:expr) => {
+                let values: [$type; 3] = [0, 1, <$type>::MAX];
+                let attribute = DenseElementsAttribute::from_slice(
+                    RankedTensorType::new(&[3], $integer_type(&context, $bits).into(), None).into(),
+                    &values,
+                )
+                .unwrap();
+
+                assert_eq!(attribute.to_vec::<$type>(), Ok(values.to_vec()));
+            };
+        }
+
+        assert_integers!(i8, 8, IntegerType::new);
+        assert_integers!(i16, 16, IntegerType::new);
+        assert_integers!(i32, 32, IntegerType::signed);
+        assert_integers!(i64, 64, IntegerType::new);
+        assert_integers!(u8, 8, IntegerType::new);
+        assert_integers!(u16, 16, IntegerType::unsigned);
+        assert_integers!(u32, 32, IntegerType::new);
+        assert_integers!(u64, 64, IntegerType::new);
+    }
+
+    #[test]
+    fn from_slice_index() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::from_slice(
+            RankedTensorType::new(&[2], Type::index(&context), None).into(),
+            &[1i64, 2],
+        )
+        .unwrap();
+
+        assert_eq!(attribute.to_vec::<i64>(), Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn from_slice_floats() {
+        let context = create_test_context();
+
+        let attribute = DenseElementsAttribute::from_slice(
+            RankedTensorType::new(&[2], Type::float32(&context), None).into(),
+            &[1.5f32, -2.0],
+        )
+        .unwrap();
+
+        assert_eq!(attribute.to_vec::<f32>(), Ok(vec![1.5, -2.0]));
+
+        let attribute = DenseElementsAttribute::from_slice(
+            RankedTensorType::new(&[2], Type::float64(&context), None).into(),
+            &[1.5f64, -2.0],
+        )
+        .unwrap();
+
+        assert_eq!(attribute.to_vec::<f64>(), Ok(vec![1.5, -2.0]));
+    }
+
+    #[test]
+    fn from_slice_bools() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::from_slice(
+            RankedTensorType::new(&[3], IntegerType::new(&context, 1).into(), None).into(),
+            &[true, false, true],
+        )
+        .unwrap();
+
+        assert_eq!(attribute.to_vec::<bool>(), Ok(vec![true, false, true]));
+    }
+
+    #[test]
+    fn from_slice_element_type_mismatch() {
+        let context = create_test_context();
+        let r#type =
+            RankedTensorType::new(&[2], IntegerType::signed(&context, 32).into(), None).into();
+
+        assert_eq!(
+            DenseElementsAttribute::from_slice(r#type, &[1u32, 2]).unwrap_err(),
+            Error::ElementExpected {
+                r#type: "u32",
+                value: r#type.to_string(),
+            }
+        );
+        assert_eq!(
+            DenseElementsAttribute::from_slice(r#type, &[1.0f32, 2.0]).unwrap_err(),
+            Error::ElementExpected {
+                r#type: "f32",
+                value: r#type.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn from_slice_element_count_mismatch() {
+        let context = create_test_context();
+
+        assert_eq!(
+            DenseElementsAttribute::from_slice(
+                RankedTensorType::new(&[3], IntegerType::new(&context, 64).into(), None).into(),
+                &[1i64, 2],
+            )
+            .unwrap_err(),
+            Error::ElementCountMismatch {
+                expected: 3,
+                actual: 2
+            }
+        );
+    }
+
+    #[test]
+    fn from_slice_dynamic_shape() {
+        let context = create_test_context();
+        let r#type = RankedTensorType::new(
+            &[i64::MIN as u64],
+            IntegerType::new(&context, 64).into(),
+            None,
+        )
+        .into();
+
+        assert_eq!(
+            DenseElementsAttribute::from_slice(r#type, &[1i64]).unwrap_err(),
+            Error::TypeExpected("statically shaped", r#type.to_string())
+        );
+    }
+
+    #[test]
+    fn from_slice_wide_integers() {
+        let context = create_test_context();
+        let r#type =
+            RankedTensorType::new(&[3], IntegerType::new(&context, 128).into(), None).into();
+        let values = [i128::MIN, -1, i128::MAX];
+        let attribute = DenseElementsAttribute::from_slice(r#type, &values).unwrap();
+
+        assert_eq!(attribute.to_vec::<i128>(), Ok(values.to_vec()));
+        assert_eq!(
+            attribute.to_vec::<u128>(),
+            Ok(values.iter().map(|&value| value as u128).collect())
+        );
+        assert_eq!(
+            attribute.to_string(),
+            "dense<[-170141183460469231731687303715884105728, -1, \
+             170141183460469231731687303715884105727]> : tensor<3xi128>"
+        );
+    }
+
+    #[test]
+    fn splat_wide_integer() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::splat(
+            RankedTensorType::new(&[2], IntegerType::unsigned(&context, 128).into(), None).into(),
+            u128::MAX,
+        )
+        .unwrap();
+
+        assert!(attribute.is_splat());
+        assert_eq!(attribute.to_vec::<u128>(), Ok(vec![u128::MAX; 2]));
+    }
+
+    #[test]
+    fn from_words() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::from_words(
+            RankedTensorType::new(&[2], IntegerType::new(&context, 192).into(), None).into(),
+            &[1, 2, 3, 4, 5, 6],
+        )
+        .unwrap();
+
+        assert_eq!(attribute.element_words(0), Ok(vec![1, 2, 3]));
+        assert_eq!(attribute.element_words(1), Ok(vec![4, 5, 6]));
+        assert_eq!(
+            attribute.element_words(2),
+            Err(Error::PositionOutOfBounds {
+                name: "dense element",
+                value: attribute.to_string(),
+                index: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn from_words_truncated() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::from_words(
+            RankedTensorType::new(&[1], IntegerType::new(&context, 72).into(), None).into(),
+            &[u64::MAX, u64::MAX],
+        )
+        .unwrap();
+
+        assert_eq!(attribute.element_words(0), Ok(vec![u64::MAX, 0xff]));
+    }
+
+    #[test]
+    fn from_words_error() {
+        let context = create_test_context();
+        let r#type =
+            RankedTensorType::new(&[2], IntegerType::new(&context, 128).into(), None).into();
+
+        assert_eq!(
+            DenseElementsAttribute::from_words(r#type, &[1, 2, 3]).unwrap_err(),
+            Error::ElementCountMismatch {
+                expected: 4,
+                actual: 3
+            }
+        );
+
+        let r#type = RankedTensorType::new(&[2], Type::float32(&context), None).into();
+
+        assert_eq!(
+            DenseElementsAttribute::from_words(r#type, &[1, 2]).unwrap_err(),
+            Error::ElementExpected {
+                r#type: "multi-bit integer",
+                value: r#type.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn from_float_bits() {
+        let context = create_test_context();
+        let r#type = RankedTensorType::new(&[3], Type::bfloat16(&context), None).into();
+        let attribute =
+            DenseElementsAttribute::from_float_bits(r#type, &[0x3f80, 0x7fc1, 0xffff_ff80])
+                .unwrap();
+
+        assert_eq!(attribute.float_element_bits(0), Ok(0x3f80));
+        assert_eq!(attribute.float_element_bits(1), Ok(0x7fc1));
+        assert_eq!(attribute.float_element_bits(2), Ok(0xff80));
+        assert_eq!(
+            attribute.float_element_bits(3),
+            Err(Error::PositionOutOfBounds {
+                name: "dense element",
+                value: attribute.to_string(),
+                index: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn from_float_bits_float8() {
+        let context = create_test_context();
+        let r#type =
+            RankedTensorType::new(&[2], FloatType::float8e4m3fn(&context).into(), None).into();
+        let attribute = DenseElementsAttribute::from_float_bits(r#type, &[0x38, 0x7f]).unwrap();
+
+        assert_eq!(attribute.float_element_bits(0), Ok(0x38));
+        assert_eq!(attribute.float_element_bits(1), Ok(0x7f));
+    }
+
+    #[test]
+    fn from_float_bits_error() {
+        let context = create_test_context();
+        let r#type = RankedTensorType::new(&[2], Type::float32(&context), None).into();
+
+        assert_eq!(
+            DenseElementsAttribute::from_float_bits(r#type, &[0]).unwrap_err(),
+            Error::ElementCountMismatch {
+                expected: 2,
+                actual: 1
+            }
+        );
+
+        let r#type =
+            RankedTensorType::new(&[2], IntegerType::new(&context, 32).into(), None).into();
+
+        assert_eq!(
+            DenseElementsAttribute::from_float_bits(r#type, &[0, 0]).unwrap_err(),
+            Error::ElementExpected {
+                r#type: "float of at most 64 bits",
+                value: r#type.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn splat() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::splat(
+            RankedTensorType::new(&[2, 3], IntegerType::new(&context, 16).into(), None).into(),
+            42i16,
+        )
+        .unwrap();
+
+        assert!(attribute.is_splat());
+        assert_eq!(attribute.to_string(), "dense<42> : tensor<2x3xi16>");
+        assert_eq!(attribute.to_vec::<i16>(), Ok(vec![42; 6]));
+    }
+
+    #[test]
+    fn splat_float() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::splat(
+            RankedTensorType::new(&[4], Type::float32(&context), None).into(),
+            1.5f32,
+        )
+        .unwrap();
+
+        assert!(attribute.is_splat());
+        assert_eq!(attribute.len(), 4);
+        assert_eq!(attribute.element::<f32>(3), Ok(1.5));
+    }
+
+    #[test]
+    fn splat_element_type_mismatch() {
+        let context = create_test_context();
+        let r#type =
+            RankedTensorType::new(&[4], IntegerType::new(&context, 32).into(), None).into();
+
+        assert_eq!(
+            DenseElementsAttribute::splat(r#type, 42i16).unwrap_err(),
+            Error::ElementExpected {
+                r#type: "i16",
+                value: r#type.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn from_raw_buffer() {
+        let context = create_test_context();
+        let data = [1i32, 2, 3]
+            .into_iter()
+            .flat_map(i32::to_ne_bytes)
+            .collect::<Vec<_>>();
+
+        let attribute = DenseElementsAttribute::from_raw_buffer(
+            RankedTensorType::new(&[3], IntegerType::new(&context, 32).into(), None).into(),
+            &data,
+        )
+        .unwrap();
+
+        assert_eq!(attribute.to_vec::<i32>(), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn from_raw_buffer_error() {
+        let context = create_test_context();
+        let r#type =
+            RankedTensorType::new(&[3], IntegerType::new(&context, 32).into(), None).into();
+
+        assert_eq!(
+            DenseElementsAttribute::from_raw_buffer(r#type, &[0; 5]).unwrap_err(),
+            Error::InvalidRawBuffer(r#type.to_string())
+        );
+    }
+
+    #[test]
+    fn from_raw_buffer_dynamic_shape() {
+        let context = create_test_context();
+        let r#type = RankedTensorType::new(
+            &[i64::MIN as u64],
+            IntegerType::new(&context, 32).into(),
+            None,
+        )
+        .into();
+
+        assert_eq!(
+            DenseElementsAttribute::from_raw_buffer(r#type, &[0; 4]).unwrap_err(),
+            Error::TypeExpected("statically shaped", "tensor<?xi32>".into())
+        );
+    }
+
+    #[test]
+    fn from_raw_buffer_unshaped() {
+        let context = create_test_context();
+        let r#type = IntegerType::new(&context, 32).into();
+
+        assert_eq!(
+            DenseElementsAttribute::from_raw_buffer(r#type, &[0; 4]).unwrap_err(),
+            Error::TypeExpected("statically shaped", "i32".into())
+        );
+    }
+
+    #[test]
+    fn element() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::from_slice(
+            RankedTensorType::new(&[2], Type::float64(&context), None).into(),
+            &[1.0f64, 2.0],
+        )
+        .unwrap();
+
+        assert_eq!(attribute.element::<f64>(1), Ok(2.0));
+        assert_eq!(
+            attribute.element::<f64>(2),
+            Err(Error::PositionOutOfBounds {
+                name: "dense element",
+                value: attribute.to_string(),
+                index: 2,
+            })
+        );
+        assert_eq!(
+            attribute.element::<i64>(0),
+            Err(Error::ElementExpected {
+                r#type: "i64",
+                value: attribute.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn is_splat() {
+        let context = create_test_context();
+        let r#type = RankedTensorType::new(&[2], IntegerType::new(&context, 8).into(), None).into();
+
+        assert!(DenseElementsAttribute::from_slice(r#type, &[1i8, 1])
+            .unwrap()
+            .is_splat());
+        assert!(!DenseElementsAttribute::from_slice(r#type, &[1i8, 2])
+            .unwrap()
+            .is_splat());
+    }
 }
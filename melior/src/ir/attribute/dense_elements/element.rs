@@ -0,0 +1,338 @@
+use crate::ir::{r#type::IntegerType, Type, TypeLike};
+use mlir_sys::{
+    mlirDenseBoolResourceElementsAttrGetValue, mlirDenseDoubleResourceElementsAttrGetValue,
+    mlirDenseElementsAttrBoolGet, mlirDenseElementsAttrBoolSplatGet,
+    mlirDenseElementsAttrDoubleGet, mlirDenseElementsAttrDoubleSplatGet,
+    mlirDenseElementsAttrFloatGet, mlirDenseElementsAttrFloatSplatGet,
+    mlirDenseElementsAttrGetBoolValue, mlirDenseElementsAttrGetDoubleValue,
+    mlirDenseElementsAttrGetFloatValue, mlirDenseElementsAttrGetInt16Value,
+    mlirDenseElementsAttrGetInt32Value, mlirDenseElementsAttrGetInt64Value,
+    mlirDenseElementsAttrGetInt8Value, mlirDenseElementsAttrGetRawData,
+    mlirDenseElementsAttrGetUInt16Value, mlirDenseElementsAttrGetUInt32Value,
+    mlirDenseElementsAttrGetUInt64Value, mlirDenseElementsAttrGetUInt8Value,
+    mlirDenseElementsAttrInt16Get, mlirDenseElementsAttrInt32Get,
+    mlirDenseElementsAttrInt32SplatGet, mlirDenseElementsAttrInt64Get,
+    mlirDenseElementsAttrInt64SplatGet, mlirDenseElementsAttrInt8Get,
+    mlirDenseElementsAttrInt8SplatGet, mlirDenseElementsAttrIsSplat,
+    mlirDenseElementsAttrRawBufferGet, mlirDenseElementsAttrSplatGet,
+    mlirDenseElementsAttrUInt16Get, mlirDenseElementsAttrUInt32Get,
+    mlirDenseElementsAttrUInt32SplatGet, mlirDenseElementsAttrUInt64Get,
+    mlirDenseElementsAttrUInt64SplatGet, mlirDenseElementsAttrUInt8Get,
+    mlirDenseElementsAttrUInt8SplatGet, mlirDenseFloatResourceElementsAttrGetValue,
+    mlirDenseInt16ResourceElementsAttrGetValue, mlirDenseInt32ResourceElementsAttrGetValue,
+    mlirDenseInt64ResourceElementsAttrGetValue, mlirDenseInt8ResourceElementsAttrGetValue,
+    mlirDenseUInt16ResourceElementsAttrGetValue, mlirDenseUInt32ResourceElementsAttrGetValue,
+    mlirDenseUInt64ResourceElementsAttrGetValue, mlirDenseUInt8ResourceElementsAttrGetValue,
+    mlirIntegerAttrGet, mlirShapedTypeGetElementType, MlirAttribute, MlirType,
+};
+use std::mem::size_of_val;
+
+/// An element of dense elements attributes.
+///
+/// It is implemented for Rust types which can be stored in and read from dense
+/// elements attributes directly.
+pub trait DenseElement: Copy {
+    /// A name of the element type.
+    const NAME: &'static str;
+
+    /// Returns `true` if an MLIR type can hold elements of this type.
+    fn is_element_type(r#type: Type) -> bool;
+
+    /// Creates a raw dense elements attribute of a shaped type.
+    ///
+    /// # Safety
+    ///
+    /// A type must be a statically shaped type of a valid element type and
+    /// with the same number of elements as values.
+    unsafe fn create(r#type: MlirType, values: &[Self]) -> MlirAttribute;
+
+    /// Creates a raw splat dense elements attribute of a shaped type.
+    ///
+    /// # Safety
+    ///
+    /// A type must be a statically shaped type of a valid element type.
+    unsafe fn splat(r#type: MlirType, value: Self) -> MlirAttribute;
+
+    /// Reads an element from a raw dense elements attribute.
+    ///
+    /// # Safety
+    ///
+    /// An attribute must be a dense elements attribute of a valid element
+    /// type and an index must be in bounds.
+    unsafe fn element(attribute: MlirAttribute, index: usize) -> Self;
+}
+
+/// An element of dense resource elements attributes.
+///
+/// It is implemented for Rust types which can be read from dense resource
+/// elements attributes directly.
+pub trait DenseResourceElement: DenseElement {
+    /// Reads an element from a raw dense resource elements attribute.
+    ///
+    /// # Safety
+    ///
+    /// An attribute must be a dense resource elements attribute of a valid
+    /// element type and an index must be in bounds.
+    unsafe fn resource_element(attribute: MlirAttribute, index: usize) -> Self;
+}
+
+fn is_integer_type(r#type: Type, width: u32, signed: bool) -> bool {
+    if r#type.is_index() {
+        width == 64
+    } else if let Ok(r#type) = IntegerType::try_from(r#type) {
+        r#type.width() == width && (r#type.is_signless() || r#type.is_signed() == signed)
+    } else {
+        false
+    }
+}
+
+macro_rules! impl_integer_element {
+    (
+        $type:ty,
+        $width:literal,
+        $signed:literal,
+        $create:ident,
+        $splat:ident,
+        $element:ident,
+        $resource_element:ident
+    ) => {
+        impl DenseElement for $type {
+            const NAME: &'static str = stringify!($type);
+
+            fn is_element_type(r#type: Type) -> bool {
+                is_integer_type(r#type, $width, $signed)
+            }
+
+            unsafe fn create(r#type: MlirType, values: &[Self]) -> MlirAttribute {
+                $create(r#type, values.len() as isize, values.as_ptr())
+            }
+
+            unsafe fn splat(r#type: MlirType, value: Self) -> MlirAttribute {
+                $splat(r#type, value)
+            }
+
+            unsafe fn element(attribute: MlirAttribute, index: usize) -> Self {
+                $element(attribute, index as isize)
+            }
+        }
+
+        impl DenseResourceElement for $type {
+            unsafe fn resource_element(attribute: MlirAttribute, index: usize) -> Self {
+                $resource_element(attribute, index as isize)
+            }
+        }
+    };
+}
+
+// The C API does not provide splat constructors for 16-bit integers.
+unsafe fn int16_splat(r#type: MlirType, value: i16) -> MlirAttribute {
+    integer_splat(r#type, value.into())
+}
+
+unsafe fn uint16_splat(r#type: MlirType, value: u16) -> MlirAttribute {
+    integer_splat(r#type, value.into())
+}
+
+unsafe fn integer_splat(r#type: MlirType, value: i64) -> MlirAttribute {
+    mlirDenseElementsAttrSplatGet(
+        r#type,
+        mlirIntegerAttrGet(mlirShapedTypeGetElementType(r#type), value),
+    )
+}
+
+impl_integer_element!(
+    i8,
+    8,
+    true,
+    mlirDenseElementsAttrInt8Get,
+    mlirDenseElementsAttrInt8SplatGet,
+    mlirDenseElementsAttrGetInt8Value,
+    mlirDenseInt8ResourceElementsAttrGetValue
+);
+impl_integer_element!(
+    i16,
+    16,
+    true,
+    mlirDenseElementsAttrInt16Get,
+    int16_splat,
+    mlirDenseElementsAttrGetInt16Value,
+    mlirDenseInt16ResourceElementsAttrGetValue
+);
+impl_integer_element!(
+    i32,
+    32,
+    true,
+    mlirDenseElementsAttrInt32Get,
+    mlirDenseElementsAttrInt32SplatGet,
+    mlirDenseElementsAttrGetInt32Value,
+    mlirDenseInt32ResourceElementsAttrGetValue
+);
+impl_integer_element!(
+    i64,
+    64,
+    true,
+    mlirDenseElementsAttrInt64Get,
+    mlirDenseElementsAttrInt64SplatGet,
+    mlirDenseElementsAttrGetInt64Value,
+    mlirDenseInt64ResourceElementsAttrGetValue
+);
+impl_integer_element!(
+    u8,
+    8,
+    false,
+    mlirDenseElementsAttrUInt8Get,
+    mlirDenseElementsAttrUInt8SplatGet,
+    mlirDenseElementsAttrGetUInt8Value,
+    mlirDenseUInt8ResourceElementsAttrGetValue
+);
+impl_integer_element!(
+    u16,
+    16,
+    false,
+    mlirDenseElementsAttrUInt16Get,
+    uint16_splat,
+    mlirDenseElementsAttrGetUInt16Value,
+    mlirDenseUInt16ResourceElementsAttrGetValue
+);
+impl_integer_element!(
+    u32,
+    32,
+    false,
+    mlirDenseElementsAttrUInt32Get,
+    mlirDenseElementsAttrUInt32SplatGet,
+    mlirDenseElementsAttrGetUInt32Value,
+    mlirDenseUInt32ResourceElementsAttrGetValue
+);
+impl_integer_element!(
+    u64,
+    64,
+    false,
+    mlirDenseElementsAttrUInt64Get,
+    mlirDenseElementsAttrUInt64SplatGet,
+    mlirDenseElementsAttrGetUInt64Value,
+    mlirDenseUInt64ResourceElementsAttrGetValue
+);
+
+impl DenseElement for f32 {
+    const NAME: &'static str = "f32";
+
+    fn is_element_type(r#type: Type) -> bool {
+        r#type.is_f32()
+    }
+
+    unsafe fn create(r#type: MlirType, values: &[Self]) -> MlirAttribute {
+        mlirDenseElementsAttrFloatGet(r#type, values.len() as isize, values.as_ptr())
+    }
+
+    unsafe fn splat(r#type: MlirType, value: Self) -> MlirAttribute {
+        mlirDenseElementsAttrFloatSplatGet(r#type, value)
+    }
+
+    unsafe fn element(attribute: MlirAttribute, index: usize) -> Self {
+        mlirDenseElementsAttrGetFloatValue(attribute, index as isize)
+    }
+}
+
+impl DenseResourceElement for f32 {
+    unsafe fn resource_element(attribute: MlirAttribute, index: usize) -> Self {
+        mlirDenseFloatResourceElementsAttrGetValue(attribute, index as isize)
+    }
+}
+
+impl DenseElement for f64 {
+    const NAME: &'static str = "f64";
+
+    fn is_element_type(r#type: Type) -> bool {
+        r#type.is_f64()
+    }
+
+    unsafe fn create(r#type: MlirType, values: &[Self]) -> MlirAttribute {
+        mlirDenseElementsAttrDoubleGet(r#type, values.len() as isize, values.as_ptr())
+    }
+
+    unsafe fn splat(r#type: MlirType, value: Self) -> MlirAttribute {
+        mlirDenseElementsAttrDoubleSplatGet(r#type, value)
+    }
+
+    unsafe fn element(attribute: MlirAttribute, index: usize) -> Self {
+        mlirDenseElementsAttrGetDoubleValue(attribute, index as isize)
+    }
+}
+
+impl DenseResourceElement for f64 {
+    unsafe fn resource_element(attribute: MlirAttribute, index: usize) -> Self {
+        mlirDenseDoubleResourceElementsAttrGetValue(attribute, index as isize)
+    }
+}
+
+impl DenseElement for bool {
+    const NAME: &'static str = "bool";
+
+    fn is_element_type(r#type: Type) -> bool {
+        IntegerType::try_from(r#type).is_ok_and(|r#type| r#type.width() == 1)
+    }
+
+    unsafe fn create(r#type: MlirType, values: &[Self]) -> MlirAttribute {
+        let values = values
+            .iter()
+            .map(|&value| i32::from(value))
+            .collect::<Vec<_>>();
+
+        mlirDenseElementsAttrBoolGet(r#type, values.len() as isize, values.as_ptr())
+    }
+
+    unsafe fn splat(r#type: MlirType, value: Self) -> MlirAttribute {
+        mlirDenseElementsAttrBoolSplatGet(r#type, value)
+    }
+
+    unsafe fn element(attribute: MlirAttribute, index: usize) -> Self {
+        mlirDenseElementsAttrGetBoolValue(attribute, index as isize)
+    }
+}
+
+impl DenseResourceElement for bool {
+    unsafe fn resource_element(attribute: MlirAttribute, index: usize) -> Self {
+        mlirDenseBoolResourceElementsAttrGetValue(attribute, index as isize)
+    }
+}
+
+macro_rules! impl_wide_integer_element {
+    ($type:ty, $signed:literal) => {
+        // The C API does not provide accessors for integers wider than 64 bits.
+        impl DenseElement for $type {
+            const NAME: &'static str = stringify!($type);
+
+            fn is_element_type(r#type: Type) -> bool {
+                is_integer_type(r#type, <$type>::BITS, $signed)
+            }
+
+            unsafe fn create(r#type: MlirType, values: &[Self]) -> MlirAttribute {
+                mlirDenseElementsAttrRawBufferGet(
+                    r#type,
+                    size_of_val(values),
+                    values.as_ptr() as *const _,
+                )
+            }
+
+            unsafe fn splat(r#type: MlirType, value: Self) -> MlirAttribute {
+                // A buffer of a single element creates a splat.
+                Self::create(r#type, &[value])
+            }
+
+            unsafe fn element(attribute: MlirAttribute, index: usize) -> Self {
+                let index = if mlirDenseElementsAttrIsSplat(attribute) {
+                    0
+                } else {
+                    index
+                };
+
+                (mlirDenseElementsAttrGetRawData(attribute) as *const Self)
+                    .add(index)
+                    .read_unaligned()
+            }
+        }
+    };
+}
+
+impl_wide_integer_element!(i128, true);
+impl_wide_integer_element!(u128, false);
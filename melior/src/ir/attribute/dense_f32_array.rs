@@ -0,0 +1,82 @@
+use super::{Attribute, AttributeLike};
+use crate::{Context, Error};
+use mlir_sys::{
+    mlirDenseArrayGetNumElements, mlirDenseF32ArrayGet, mlirDenseF32ArrayGetElement, MlirAttribute,
+};
+
+/// A dense f32 array attribute.
+#[derive(Clone, Copy)]
+pub struct DenseF32ArrayAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> DenseF32ArrayAttribute<'c> {
+    /// Creates a dense f32 array attribute.
+    pub fn new(context: &'c Context, values: &[f32]) -> Self {
+        unsafe {
+            Self::from_raw(mlirDenseF32ArrayGet(
+                context.to_raw(),
+                values.len() as isize,
+                values.as_ptr(),
+            ))
+        }
+    }
+
+    /// Returns a length.
+    pub fn len(&self) -> usize {
+        (unsafe { mlirDenseArrayGetNumElements(self.attribute.to_raw()) }) as usize
+    }
+
+    /// Checks if an array is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an element.
+    pub fn element(&self, index: usize) -> Result<f32, Error> {
+        if index < self.len() {
+            Ok(unsafe { mlirDenseF32ArrayGetElement(self.attribute.to_raw(), index as isize) })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "array element",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+}
+
+attribute_traits!(
+    DenseF32ArrayAttribute,
+    is_dense_f32_array,
+    "dense f32 array"
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::test::create_test_context;
+
+    use super::*;
+
+    #[test]
+    fn element() {
+        let context = create_test_context();
+        let attribute = DenseF32ArrayAttribute::new(&context, &[1.0, 2.0, 3.0]);
+
+        assert_eq!(attribute.element(0).unwrap(), 1.0);
+        assert_eq!(attribute.element(1).unwrap(), 2.0);
+        assert_eq!(attribute.element(2).unwrap(), 3.0);
+        assert!(matches!(
+            attribute.element(3),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn len() {
+        let context = create_test_context();
+        let attribute = DenseF32ArrayAttribute::new(&context, &[1.0, 2.0, 3.0]);
+
+        assert_eq!(attribute.len(), 3);
+    }
+}
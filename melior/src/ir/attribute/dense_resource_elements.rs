@@ -0,0 +1,413 @@
+use super::{
+    Attribute, AttributeClass, AttributeLike, DenseElement, DenseElementsAttribute,
+    DenseResourceElement,
+};
+use crate::{
+    ir::{Type, TypeLike},
+    Error, StringRef,
+};
+use mlir_sys::{
+    mlirElementsAttrGetNumElements, mlirShapedTypeGetElementType,
+    mlirUnmanagedDenseResourceElementsAttrGet, MlirAttribute,
+};
+use std::{
+    ffi::c_void,
+    fmt::{self, Debug, Display, Formatter},
+    mem::{align_of, size_of, size_of_val},
+    ptr::{null_mut, slice_from_raw_parts_mut},
+    sync::OnceLock,
+};
+
+/// A dense resource elements attribute.
+///
+/// Its data is not copied into a context but referenced as a resource blob,
+/// which is printed in the `dialect_resources` section of an IR file.
+#[derive(Clone, Copy)]
+pub struct DenseResourceElementsAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> DenseResourceElementsAttribute<'c> {
+    /// Creates a dense resource elements attribute owning data.
+    ///
+    /// The data is dropped when the resource is released by a context. A name
+    /// of the resource might be uniqued by a context.
+    pub fn new<T: DenseElement>(
+        r#type: Type<'c>,
+        name: &str,
+        data: impl Into<Box<[T]>>,
+    ) -> Result<Self, Error> {
+        unsafe extern "C" fn drop_data<T>(
+            _user_data: *mut c_void,
+            data: *const c_void,
+            size: usize,
+            _alignment: usize,
+        ) {
+            drop(Box::from_raw(slice_from_raw_parts_mut(
+                data as *mut T,
+                size / size_of::<T>(),
+            )));
+        }
+
+        let data = data.into();
+        Self::check_type::<T>(r#type, data.len())?;
+        let size = size_of_val(&*data);
+
+        Ok(unsafe {
+            Self::from_raw(mlirUnmanagedDenseResourceElementsAttrGet(
+                r#type.to_raw(),
+                StringRef::new(name).to_raw(),
+                Box::into_raw(data) as *mut c_void,
+                size,
+                align_of::<T>(),
+                false,
+                Some(drop_data::<T>),
+                null_mut(),
+            ))
+        })
+    }
+
+    /// Creates a dense resource elements attribute borrowing static data.
+    pub fn from_static<T: DenseElement>(
+        r#type: Type<'c>,
+        name: &str,
+        data: &'static [T],
+    ) -> Result<Self, Error> {
+        Self::check_type::<T>(r#type, data.len())?;
+
+        Ok(unsafe {
+            Self::from_raw(mlirUnmanagedDenseResourceElementsAttrGet(
+                r#type.to_raw(),
+                StringRef::new(name).to_raw(),
+                data.as_ptr() as *mut c_void,
+                size_of_val(data),
+                align_of::<T>(),
+                false,
+                None,
+                null_mut(),
+            ))
+        })
+    }
+
+    /// Returns a length.
+    pub fn len(&self) -> usize {
+        (unsafe { mlirElementsAttrGetNumElements(self.attribute.to_raw()) }) as usize
+    }
+
+    /// Checks if an array is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an element.
+    pub fn element<T: DenseResourceElement>(&self, index: usize) -> Result<T, Error> {
+        self.check_element_type::<T>()?;
+
+        if index < self.len() {
+            Ok(unsafe { T::resource_element(self.to_raw(), index) })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "dense resource element",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns all elements.
+    pub fn elements<T: DenseResourceElement>(&self) -> Result<impl Iterator<Item = T> + '_, Error> {
+        self.check_element_type::<T>()?;
+
+        Ok((0..self.len()).map(|index| unsafe { T::resource_element(self.to_raw(), index) }))
+    }
+
+    /// Converts elements into a vector.
+    pub fn to_vec<T: DenseResourceElement>(&self) -> Result<Vec<T>, Error> {
+        Ok(self.elements()?.collect())
+    }
+
+    fn check_element_type<T: DenseResourceElement>(&self) -> Result<(), Error> {
+        let r#type =
+            unsafe { Type::from_raw(mlirShapedTypeGetElementType(self.r#type().to_raw())) };
+
+        // Resource elements of an index type cannot be read via the C API.
+        if !r#type.is_index() && T::is_element_type(r#type) {
+            Ok(())
+        } else {
+            Err(Error::ElementExpected {
+                r#type: T::NAME,
+                value: self.to_string(),
+            })
+        }
+    }
+
+    fn check_type<T: DenseElement>(r#type: Type<'c>, length: usize) -> Result<(), Error> {
+        let count = DenseElementsAttribute::check_type::<T>(r#type)?;
+
+        if length == count {
+            Ok(())
+        } else {
+            Err(Error::ElementCountMismatch {
+                expected: count,
+                actual: length,
+            })
+        }
+    }
+
+    unsafe fn from_raw(raw: MlirAttribute) -> Self {
+        Self {
+            attribute: Attribute::from_raw(raw),
+        }
+    }
+}
+
+// The C API provides neither a check function nor a type ID for dense
+// resource elements attributes. So we compare type IDs with the one of an
+// attribute with an elided resource instead. It is cached on the first check
+// as type IDs are unique in a process rather than in a context.
+fn is_dense_resource_elements(attribute: Attribute) -> bool {
+    static TYPE_ID: OnceLock<usize> = OnceLock::new();
+
+    attribute.is_elements()
+        && *TYPE_ID.get_or_init(|| {
+            raw_type_id(
+                Attribute::parse(
+                    unsafe { attribute.context().to_ref() },
+                    "dense_resource<__elided__> : tensor<1xi8>",
+                )
+                .expect("valid dense resource elements attribute"),
+            )
+        }) == raw_type_id(attribute)
+}
+
+fn raw_type_id(attribute: Attribute) -> usize {
+    attribute.type_id().to_raw().ptr as usize
+}
+
+impl<'c> TryFrom<Attribute<'c>> for DenseResourceElementsAttribute<'c> {
+    type Error = Error;
+
+    fn try_from(attribute: Attribute<'c>) -> Result<Self, Self::Error> {
+        if Self::is_instance(attribute) {
+            Ok(unsafe { Self::from_raw(attribute.to_raw()) })
+        } else {
+            Err(Error::AttributeExpected(
+                "dense resource elements",
+                attribute.to_string(),
+            ))
+        }
+    }
+}
+
+impl<'c> AttributeClass<'c> for DenseResourceElementsAttribute<'c> {
+    fn is_instance(attribute: Attribute<'c>) -> bool {
+        is_dense_resource_elements(attribute)
+    }
+}
+
+impl<'c> AttributeLike<'c> for DenseResourceElementsAttribute<'c> {
+    fn to_raw(&self) -> MlirAttribute {
+        self.attribute.to_raw()
+    }
+}
+
+impl Display for DenseResourceElementsAttribute<'_> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.attribute, formatter)
+    }
+}
+
+impl Debug for DenseResourceElementsAttribute<'_> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{
+            operation::OperationBuilder, r#type::RankedTensorType, BlockLike, Identifier, Location,
+            Module,
+        },
+        test::create_test_context,
+    };
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+        let attribute = DenseResourceElementsAttribute::new(
+            RankedTensorType::new(&[3], Type::parse(&context, "i64").unwrap(), None).into(),
+            "foo",
+            vec![1i64, 2, 3],
+        )
+        .unwrap();
+
+        assert_eq!(attribute.to_string(), "dense_resource<foo> : tensor<3xi64>");
+        assert_eq!(attribute.len(), 3);
+        assert_eq!(attribute.to_vec::<i64>(), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn new_boxed_slice() {
+        let context = create_test_context();
+        let attribute = DenseResourceElementsAttribute::new(
+            RankedTensorType::new(&[2], Type::float32(&context), None).into(),
+            "foo",
+            vec![1.5f32, 2.5].into_boxed_slice(),
+        )
+        .unwrap();
+
+        assert_eq!(attribute.element::<f32>(1), Ok(2.5));
+    }
+
+    #[test]
+    fn from_static() {
+        static DATA: [u8; 4] = [1, 2, 3, 4];
+
+        let context = create_test_context();
+        let attribute = DenseResourceElementsAttribute::from_static(
+            RankedTensorType::new(&[4], Type::parse(&context, "ui8").unwrap(), None).into(),
+            "foo",
+            &DATA,
+        )
+        .unwrap();
+
+        assert_eq!(attribute.to_vec::<u8>(), Ok(DATA.to_vec()));
+    }
+
+    #[test]
+    fn new_element_count_mismatch() {
+        let context = create_test_context();
+
+        assert_eq!(
+            DenseResourceElementsAttribute::new(
+                RankedTensorType::new(&[3], Type::float64(&context), None).into(),
+                "foo",
+                vec![1.0f64],
+            )
+            .unwrap_err(),
+            Error::ElementCountMismatch {
+                expected: 3,
+                actual: 1
+            }
+        );
+    }
+
+    #[test]
+    fn element_error() {
+        let context = create_test_context();
+        let attribute = DenseResourceElementsAttribute::new(
+            RankedTensorType::new(&[1], Type::float64(&context), None).into(),
+            "foo",
+            vec![1.0f64],
+        )
+        .unwrap();
+
+        assert!(matches!(
+            attribute.element::<f64>(1),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+        assert!(matches!(
+            attribute.element::<i64>(0),
+            Err(Error::ElementExpected { .. })
+        ));
+    }
+
+    #[test]
+    fn print() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let module = Module::new(location);
+
+        module.body().append_operation(
+            OperationBuilder::new("foo", location)
+                .add_attributes(&[(
+                    Identifier::new(&context, "value"),
+                    DenseResourceElementsAttribute::new(
+                        RankedTensorType::new(&[2], Type::parse(&context, "i32").unwrap(), None)
+                            .into(),
+                        "blob",
+                        vec![1i32, 2],
+                    )
+                    .unwrap()
+                    .into(),
+                )])
+                .build()
+                .unwrap(),
+        );
+
+        let string = module.as_operation().to_string();
+
+        assert!(string.contains("{value = dense_resource<blob> : tensor<2xi32>}"));
+        assert!(string.contains("dialect_resources"));
+        assert!(string.contains(r#"blob: "0x040000000100000002000000""#));
+    }
+
+    #[test]
+    fn parse() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let module = Module::parse(
+            &context,
+            indoc!(
+                r#"
+                module {
+                  "foo"() {value = dense_resource<blob> : tensor<2xi32>} : () -> ()
+                }
+
+                {-#
+                  dialect_resources: {
+                    builtin: {
+                      blob: "0x040000000100000002000000"
+                    }
+                  }
+                #-}
+                "#
+            ),
+        )
+        .unwrap();
+
+        let attribute = DenseResourceElementsAttribute::try_from(
+            module
+                .body()
+                .first_operation()
+                .unwrap()
+                .attribute("value")
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(attribute.to_vec::<i32>(), Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn try_from_error() {
+        let context = create_test_context();
+
+        assert!(DenseResourceElementsAttribute::try_from(
+            Attribute::parse(&context, "dense<1> : tensor<2xi32>").unwrap()
+        )
+        .is_err());
+        assert!(DenseResourceElementsAttribute::try_from(Attribute::unit(&context)).is_err());
+    }
+
+    #[test]
+    fn drop_with_context() {
+        let context = create_test_context();
+
+        DenseResourceElementsAttribute::new(
+            RankedTensorType::new(&[2], Type::float32(&context), None).into(),
+            "foo",
+            vec![1.0f32; 2],
+        )
+        .unwrap();
+
+        drop(context);
+    }
+}
@@ -0,0 +1,175 @@
+use super::{Attribute, AttributeLike};
+use crate::{ir::Identifier, Context, Error, StringRef};
+use mlir_sys::{
+    mlirDictionaryAttrGet, mlirDictionaryAttrGetElement, mlirDictionaryAttrGetElementByName,
+    mlirDictionaryAttrGetNumElements, mlirNamedAttributeGet, MlirAttribute,
+};
+
+/// A dictionary attribute.
+#[derive(Clone, Copy)]
+pub struct DictionaryAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> DictionaryAttribute<'c> {
+    /// Creates a dictionary attribute.
+    pub fn new(context: &'c Context, values: &[(Identifier<'c>, Attribute<'c>)]) -> Self {
+        let values = values
+            .iter()
+            .map(|(name, attribute)| unsafe {
+                mlirNamedAttributeGet(name.to_raw(), attribute.to_raw())
+            })
+            .collect::<Vec<_>>();
+
+        unsafe {
+            Self::from_raw(mlirDictionaryAttrGet(
+                context.to_raw(),
+                values.len() as isize,
+                values.as_ptr(),
+            ))
+        }
+    }
+
+    /// Returns a length.
+    pub fn len(&self) -> usize {
+        (unsafe { mlirDictionaryAttrGetNumElements(self.attribute.to_raw()) }) as usize
+    }
+
+    /// Checks if a dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an element.
+    pub fn element(&self, index: usize) -> Result<(Identifier<'c>, Attribute<'c>), Error> {
+        if index < self.len() {
+            Ok(unsafe {
+                let named_attribute =
+                    mlirDictionaryAttrGetElement(self.attribute.to_raw(), index as isize);
+
+                (
+                    Identifier::from_raw(named_attribute.name),
+                    Attribute::from_raw(named_attribute.attribute),
+                )
+            })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "dictionary element",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns all elements.
+    pub fn elements(&self) -> impl Iterator<Item = (Identifier<'c>, Attribute<'c>)> + '_ {
+        (0..self.len()).map(|index| self.element(index).expect("valid element index"))
+    }
+
+    /// Returns an attribute with the given name.
+    pub fn attribute(&self, name: &str) -> Result<Attribute<'c>, Error> {
+        unsafe {
+            Attribute::from_option_raw(mlirDictionaryAttrGetElementByName(
+                self.attribute.to_raw(),
+                StringRef::new(name).to_raw(),
+            ))
+        }
+        .ok_or_else(|| Error::AttributeNotFound(name.into()))
+    }
+}
+
+attribute_traits!(DictionaryAttribute, is_dictionary, "dictionary");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::attribute::StringAttribute, test::create_test_context};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+        let attribute = DictionaryAttribute::new(
+            &context,
+            &[
+                (
+                    Identifier::new(&context, "foo"),
+                    StringAttribute::new(&context, "bar").into(),
+                ),
+                (Identifier::new(&context, "baz"), Attribute::unit(&context)),
+            ],
+        );
+
+        assert_eq!(attribute.to_string(), "{baz, foo = \"bar\"}");
+    }
+
+    #[test]
+    fn element() {
+        let context = create_test_context();
+        let value = Attribute::unit(&context);
+        let attribute =
+            DictionaryAttribute::new(&context, &[(Identifier::new(&context, "foo"), value)]);
+
+        assert_eq!(
+            attribute.element(0).unwrap(),
+            (Identifier::new(&context, "foo"), value)
+        );
+        assert!(matches!(
+            attribute.element(1),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn elements() {
+        let context = create_test_context();
+        let attribute = DictionaryAttribute::new(
+            &context,
+            &[
+                (Identifier::new(&context, "foo"), Attribute::unit(&context)),
+                (Identifier::new(&context, "bar"), Attribute::unit(&context)),
+            ],
+        );
+
+        assert_eq!(
+            attribute
+                .elements()
+                .map(|(name, _)| name.as_string_ref().as_str().unwrap().to_owned())
+                .collect::<Vec<_>>(),
+            vec!["bar", "foo"]
+        );
+    }
+
+    #[test]
+    fn attribute() {
+        let context = create_test_context();
+        let value = StringAttribute::new(&context, "bar").into();
+        let attribute =
+            DictionaryAttribute::new(&context, &[(Identifier::new(&context, "foo"), value)]);
+
+        assert_eq!(attribute.attribute("foo"), Ok(value));
+        assert_eq!(
+            attribute.attribute("bar"),
+            Err(Error::AttributeNotFound("bar".into()))
+        );
+    }
+
+    #[test]
+    fn len() {
+        let context = create_test_context();
+
+        assert_eq!(DictionaryAttribute::new(&context, &[]).len(), 0);
+        assert!(DictionaryAttribute::new(&context, &[]).is_empty());
+    }
+
+    #[test]
+    fn try_from() {
+        let context = create_test_context();
+
+        assert!(DictionaryAttribute::try_from(
+            Attribute::parse(&context, "{foo = 42 : i64}").unwrap()
+        )
+        .is_ok());
+        assert!(DictionaryAttribute::try_from(Attribute::unit(&context)).is_err());
+    }
+}
@@ -1,9 +1,15 @@
-use super::{Attribute, AttributeLike};
+use super::{Attribute, AttributeLike, DenseElementsAttribute};
 use crate::{
-    ir::{Type, TypeLike},
+    ir::{
+        r#type::{FloatType, RankedTensorType},
+        Type, TypeLike,
+    },
     Context, Error,
 };
-use mlir_sys::{mlirFloatAttrDoubleGet, mlirFloatAttrGetValueDouble, MlirAttribute};
+use mlir_sys::{
+    mlirDenseElementsAttrGetSplatValue, mlirFloatAttrDoubleGet, mlirFloatAttrGetValueDouble,
+    MlirAttribute,
+};
 
 /// A float attribute.
 #[derive(Clone, Copy)]
@@ -23,10 +29,52 @@ impl<'c> FloatAttribute<'c> {
         }
     }
 
+    /// Creates a float attribute from a bit pattern.
+    ///
+    /// Bits beyond the width of a type are ignored. Float types wider than 64
+    /// bits, such as `f80` and `f128`, are not supported as their bit patterns
+    /// do not fit in `u64`.
+    pub fn from_bits(r#type: Type<'c>, bits: u64) -> Result<Self, Error> {
+        check_width(r#type)?;
+
+        let attribute = DenseElementsAttribute::from_float_bits(
+            RankedTensorType::new(&[], r#type, None).into(),
+            &[bits],
+        )?;
+
+        Ok(unsafe { Self::from_raw(mlirDenseElementsAttrGetSplatValue(attribute.to_raw())) })
+    }
+
     /// Returns a value.
     pub fn value(&self) -> f64 {
         unsafe { mlirFloatAttrGetValueDouble(self.to_raw()) }
     }
+
+    /// Returns a bit pattern of a value.
+    ///
+    /// Float types wider than 64 bits, such as `f80` and `f128`, are not
+    /// supported as their bit patterns do not fit in `u64`.
+    pub fn to_bits(&self) -> Result<u64, Error> {
+        check_width(self.r#type())?;
+
+        // The C API exposes values only as doubles, so they are read from dense
+        // elements storage instead.
+        DenseElementsAttribute::new(
+            RankedTensorType::new(&[], self.r#type(), None).into(),
+            &[self.attribute],
+        )?
+        .float_element_bits(0)
+    }
+}
+
+fn check_width(r#type: Type) -> Result<(), Error> {
+    match FloatType::try_from(r#type) {
+        Ok(float) if float.width() <= u64::BITS => Ok(()),
+        _ => Err(Error::TypeExpected(
+            "float of at most 64 bits",
+            r#type.to_string(),
+        )),
+    }
 }
 
 attribute_traits!(FloatAttribute, is_float, "float");
@@ -34,7 +82,7 @@ attribute_traits!(FloatAttribute, is_float, "float");
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test::create_test_context;
+    use crate::{ir::r#type::IntegerType, test::create_test_context};
 
     #[test]
     fn value() {
@@ -45,4 +93,71 @@ mod tests {
             42.0
         );
     }
+
+    #[test]
+    fn from_bits() {
+        let context = create_test_context();
+
+        for (r#type, bits) in [
+            (Type::bfloat16(&context), 0x3f80),
+            (Type::float16(&context), 0x7e01),
+            (Type::float32(&context), 0x7fc0_0001),
+            (Type::float64(&context), 0xfff8_0000_0000_0001),
+            (FloatType::float8e5m2(&context).into(), 0x3c),
+            (FloatType::float8e4m3fnuz(&context).into(), 0x80),
+            (FloatType::tf32(&context).into(), 0x3_8000),
+        ] {
+            assert_eq!(
+                FloatAttribute::from_bits(r#type, bits).unwrap().to_bits(),
+                Ok(bits)
+            );
+        }
+    }
+
+    #[test]
+    fn from_bits_value() {
+        let context = create_test_context();
+
+        assert_eq!(
+            FloatAttribute::from_bits(Type::float16(&context), 0x3c00)
+                .unwrap()
+                .value(),
+            1.0
+        );
+        assert_eq!(
+            FloatAttribute::from_bits(Type::bfloat16(&context), 0x3f80)
+                .unwrap()
+                .to_string(),
+            "1.000000e+00 : bf16"
+        );
+    }
+
+    #[test]
+    fn from_bits_error() {
+        let context = create_test_context();
+        let r#type = IntegerType::new(&context, 32).into();
+
+        assert_eq!(
+            FloatAttribute::from_bits(r#type, 0).unwrap_err(),
+            Error::TypeExpected("float of at most 64 bits", "i32".into())
+        );
+    }
+
+    #[test]
+    fn wide_float_bits() {
+        let context = create_test_context();
+        let r#type = Type::parse(&context, "f128").unwrap();
+
+        assert_eq!(
+            FloatAttribute::from_bits(r#type, 0).unwrap_err(),
+            Error::TypeExpected("float of at most 64 bits", "f128".into())
+        );
+        assert_eq!(
+            FloatAttribute::new(&context, r#type, 1.0).to_bits(),
+            Err(Error::TypeExpected(
+                "float of at most 64 bits",
+                "f128".into()
+            ))
+        );
+    }
 }
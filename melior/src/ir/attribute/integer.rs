@@ -1,12 +1,20 @@
-use super::{Attribute, AttributeLike};
+mod words;
+
+use self::words::{extend, is_negative, truncate, word_count};
+use super::{Attribute, AttributeLike, DenseElementsAttribute};
 use crate::{
-    ir::{Type, TypeLike},
+    ir::{
+        r#type::{IntegerType, RankedTensorType},
+        Type, TypeLike,
+    },
     Error,
 };
 use mlir_sys::{
-    mlirIntegerAttrGet, mlirIntegerAttrGetValueInt, mlirIntegerAttrGetValueSInt,
-    mlirIntegerAttrGetValueUInt, MlirAttribute,
+    mlirDenseElementsAttrGetRawData, mlirDenseElementsAttrGetSplatValue,
+    mlirDenseElementsAttrSplatGet, mlirIntegerAttrGet, mlirIntegerAttrGetValueInt,
+    mlirIntegerAttrGetValueSInt, mlirIntegerAttrGetValueUInt, MlirAttribute,
 };
+use std::slice;
 
 /// An integer attribute.
 #[derive(Clone, Copy)]
@@ -20,6 +28,64 @@ impl<'c> IntegerAttribute<'c> {
         unsafe { Self::from_raw(mlirIntegerAttrGet(r#type.to_raw(), integer)) }
     }
 
+    /// Creates an integer attribute from an unsigned integer.
+    ///
+    /// A value is truncated to the width of a type.
+    pub fn from_u64(r#type: Type<'c>, integer: u64) -> Result<Self, Error> {
+        Self::from_double_word(r#type, [integer, 0], false)
+    }
+
+    /// Creates an integer attribute from a 128-bit signed integer.
+    ///
+    /// A value is truncated to the width of a type.
+    pub fn from_i128(r#type: Type<'c>, integer: i128) -> Result<Self, Error> {
+        Self::from_double_word(r#type, [integer as u64, (integer >> 64) as u64], true)
+    }
+
+    /// Creates an integer attribute from a 128-bit unsigned integer.
+    ///
+    /// A value is truncated to the width of a type.
+    pub fn from_u128(r#type: Type<'c>, integer: u128) -> Result<Self, Error> {
+        Self::from_double_word(r#type, [integer as u64, (integer >> 64) as u64], false)
+    }
+
+    /// Creates an integer attribute from little-endian 64-bit words of a two's
+    /// complement integer.
+    ///
+    /// The number of words must match the width of a type. Bits beyond the
+    /// width are ignored.
+    pub fn from_words(r#type: Type<'c>, words: &[u64]) -> Result<Self, Error> {
+        let width = integer_width(r#type)?;
+        let count = word_count(width);
+
+        if words.len() != count {
+            return Err(Error::ElementCountMismatch {
+                expected: count,
+                actual: words.len(),
+            });
+        }
+
+        if width <= 64 {
+            // Zero-width integers have no words.
+            let mut words = words.to_vec();
+            words.resize(1, 0);
+            truncate(&mut words, width);
+
+            let value = extend(&words, width, 1, is_signed(r#type)).unwrap_or(words)[0];
+
+            Ok(unsafe { Self::from_raw(mlirIntegerAttrGet(r#type.to_raw(), value as i64)) })
+        } else {
+            // The C API does not accept values wider than 64 bits, so they are
+            // written into dense elements storage instead.
+            let attribute = DenseElementsAttribute::from_words(
+                RankedTensorType::new(&[], r#type, None).into(),
+                words,
+            )?;
+
+            Ok(unsafe { Self::from_raw(mlirDenseElementsAttrGetSplatValue(attribute.to_raw())) })
+        }
+    }
+
     /// Returns a value.
     pub fn value(&self) -> i64 {
         unsafe { mlirIntegerAttrGetValueInt(self.to_raw()) }
@@ -34,6 +100,118 @@ impl<'c> IntegerAttribute<'c> {
     pub fn unsigned_value(&self) -> u64 {
         unsafe { mlirIntegerAttrGetValueUInt(self.to_raw()) }
     }
+
+    /// Returns a value as a 128-bit signed integer.
+    ///
+    /// Signless integers are sign-extended. It returns `None` if a value does
+    /// not fit.
+    pub fn to_i128(&self) -> Option<i128> {
+        let unsigned = is_unsigned(self.r#type());
+        let words = extend(&self.to_words(), self.width(), 2, !unsigned)?;
+
+        if unsigned && is_negative(&words, 128) {
+            None
+        } else {
+            Some((u128::from(words[0]) | (u128::from(words[1]) << 64)) as i128)
+        }
+    }
+
+    /// Returns a value as a 128-bit unsigned integer.
+    ///
+    /// Signless integers are zero-extended. It returns `None` if a value does
+    /// not fit.
+    pub fn to_u128(&self) -> Option<u128> {
+        let words = self.to_words();
+
+        if is_signed(self.r#type()) && is_negative(&words, self.width()) {
+            return None;
+        }
+
+        let words = extend(&words, self.width(), 2, false)?;
+
+        Some(u128::from(words[0]) | (u128::from(words[1]) << 64))
+    }
+
+    /// Returns little-endian 64-bit words of a value as a two's complement
+    /// integer.
+    pub fn to_words(&self) -> Vec<u64> {
+        let r#type = self.r#type();
+        let width = self.width();
+
+        let mut words = if width <= 64 {
+            vec![if is_signed(r#type) {
+                self.signed_value() as u64
+            } else if is_unsigned(r#type) {
+                self.unsigned_value()
+            } else {
+                self.value() as u64
+            }]
+        } else {
+            // The C API does not expose values wider than 64 bits, so they are
+            // read from dense elements storage instead.
+            let byte_count = width.div_ceil(8);
+            let data = unsafe {
+                let attribute = mlirDenseElementsAttrSplatGet(
+                    RankedTensorType::new(&[], r#type, None).to_raw(),
+                    self.to_raw(),
+                );
+
+                slice::from_raw_parts(
+                    mlirDenseElementsAttrGetRawData(attribute) as *const u8,
+                    byte_count,
+                )
+            };
+
+            data.chunks(8)
+                .map(|chunk| {
+                    let mut bytes = [0; 8];
+                    bytes[..chunk.len()].copy_from_slice(chunk);
+                    u64::from_le_bytes(bytes)
+                })
+                .collect()
+        };
+
+        words.truncate(word_count(width));
+        truncate(&mut words, width);
+
+        words
+    }
+
+    fn width(&self) -> usize {
+        integer_width(self.r#type()).expect("integer type")
+    }
+
+    fn from_double_word(r#type: Type<'c>, words: [u64; 2], signed: bool) -> Result<Self, Error> {
+        let count = word_count(integer_width(r#type)?);
+        let extension = if signed && (words[1] as i64) < 0 {
+            u64::MAX
+        } else {
+            0
+        };
+        let mut words = words.to_vec();
+
+        words.resize(count.max(words.len()), extension);
+        words.truncate(count);
+
+        Self::from_words(r#type, &words)
+    }
+}
+
+fn integer_width(r#type: Type) -> Result<usize, Error> {
+    if r#type.is_index() {
+        // An internal storage width of index types
+        Ok(64)
+    } else {
+        Ok(IntegerType::try_from(r#type)?.width() as usize)
+    }
+}
+
+fn is_signed(r#type: Type) -> bool {
+    IntegerType::try_from(r#type).is_ok_and(|r#type| r#type.is_signed())
+}
+
+fn is_unsigned(r#type: Type) -> bool {
+    IntegerType::try_from(r#type).is_ok_and(|r#type| r#type.is_unsigned())
 }
 
 attribute_traits!(IntegerAttribute, is_integer, "integer");
@@ -41,7 +219,7 @@ attribute_traits!(IntegerAttribute, is_integer, "integer");
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{ir::r#type::IntegerType, test::create_test_context};
+    use crate::test::create_test_context;
 
     #[test]
     fn value() {
@@ -52,4 +230,131 @@ mod tests {
             42
         );
     }
+
+    #[test]
+    fn from_u64() {
+        let context = create_test_context();
+        let attribute =
+            IntegerAttribute::from_u64(IntegerType::unsigned(&context, 64).into(), u64::MAX)
+                .unwrap();
+
+        assert_eq!(attribute.unsigned_value(), u64::MAX);
+        assert_eq!(attribute.to_u128(), Some(u64::MAX.into()));
+        assert_eq!(attribute.to_string(), "18446744073709551615 : ui64");
+    }
+
+    #[test]
+    fn from_i128() {
+        let context = create_test_context();
+
+        for (r#type, value) in [
+            (IntegerType::new(&context, 128), i128::MIN),
+            (IntegerType::new(&context, 128), -1),
+            (IntegerType::signed(&context, 128), i128::MAX),
+            (IntegerType::signed(&context, 128), -42),
+            (IntegerType::new(&context, 8), -1),
+        ] {
+            assert_eq!(
+                IntegerAttribute::from_i128(r#type.into(), value)
+                    .unwrap()
+                    .to_i128(),
+                Some(value)
+            );
+        }
+
+        assert_eq!(
+            IntegerAttribute::from_i128(IntegerType::signed(&context, 128).into(), i128::MIN)
+                .unwrap()
+                .to_string(),
+            "-170141183460469231731687303715884105728 : si128"
+        );
+    }
+
+    #[test]
+    fn from_u128() {
+        let context = create_test_context();
+        let attribute =
+            IntegerAttribute::from_u128(IntegerType::unsigned(&context, 128).into(), u128::MAX)
+                .unwrap();
+
+        assert_eq!(attribute.to_u128(), Some(u128::MAX));
+        assert_eq!(attribute.to_i128(), None);
+        assert_eq!(
+            attribute.to_string(),
+            "340282366920938463463374607431768211455 : ui128"
+        );
+    }
+
+    #[test]
+    fn from_words() {
+        let context = create_test_context();
+        let r#type = IntegerType::new(&context, 256).into();
+        let words = [1, 2, 3, u64::MAX];
+
+        assert_eq!(
+            IntegerAttribute::from_words(r#type, &words)
+                .unwrap()
+                .to_words(),
+            words
+        );
+        assert_eq!(
+            IntegerAttribute::from_words(r#type, &[1]).unwrap_err(),
+            Error::ElementCountMismatch {
+                expected: 4,
+                actual: 1
+            }
+        );
+    }
+
+    #[test]
+    fn from_words_truncated() {
+        let context = create_test_context();
+
+        assert_eq!(
+            IntegerAttribute::from_words(IntegerType::new(&context, 65).into(), &[0, u64::MAX])
+                .unwrap()
+                .to_words(),
+            [0, 1]
+        );
+    }
+
+    #[test]
+    fn from_words_non_integer() {
+        let context = create_test_context();
+
+        assert_eq!(
+            IntegerAttribute::from_words(Type::float64(&context), &[0]).unwrap_err(),
+            Error::TypeExpected("integer", "f64".into())
+        );
+    }
+
+    #[test]
+    fn to_i128_overflow() {
+        let context = create_test_context();
+
+        assert_eq!(
+            IntegerAttribute::from_words(IntegerType::new(&context, 192).into(), &[0, 0, 1])
+                .unwrap()
+                .to_i128(),
+            None
+        );
+    }
+
+    #[test]
+    fn to_u128_negative() {
+        let context = create_test_context();
+
+        assert_eq!(
+            IntegerAttribute::from_i128(IntegerType::signed(&context, 128).into(), -1)
+                .unwrap()
+                .to_u128(),
+            None
+        );
+        assert_eq!(
+            IntegerAttribute::from_i128(IntegerType::new(&context, 128).into(), -1)
+                .unwrap()
+                .to_u128(),
+            Some(u128::MAX)
+        );
+    }
 }
@@ -0,0 +1,77 @@
+//! Conversions of little-endian words of arbitrary-precision integers.
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Returns a number of words for a bit width.
+pub fn word_count(width: usize) -> usize {
+    width.div_ceil(WORD_BITS)
+}
+
+/// Clears bits beyond a bit width.
+pub fn truncate(words: &mut [u64], width: usize) {
+    let bits = width % WORD_BITS;
+
+    if bits != 0 {
+        if let Some(word) = words.last_mut() {
+            *word &= (1 << bits) - 1;
+        }
+    }
+}
+
+/// Returns `true` if a sign bit of a bit width is set.
+pub fn is_negative(words: &[u64], width: usize) -> bool {
+    width > 0 && words[(width - 1) / WORD_BITS] >> ((width - 1) % WORD_BITS) & 1 == 1
+}
+
+/// Sign-extends or zero-extends words of a bit width into a number of words.
+pub fn extend(words: &[u64], width: usize, count: usize, signed: bool) -> Option<Vec<u64>> {
+    let extension = if signed && is_negative(words, width) {
+        u64::MAX
+    } else {
+        0
+    };
+    let mut words = words.to_vec();
+    let bits = width % WORD_BITS;
+
+    if bits != 0 && extension != 0 {
+        if let Some(word) = words.last_mut() {
+            *word |= u64::MAX << bits;
+        }
+    }
+
+    if words.len() > count {
+        // Truncated words must be redundant extensions of the rest.
+        let sign = if signed && words[count - 1] >> (WORD_BITS - 1) == 1 {
+            u64::MAX
+        } else {
+            0
+        };
+
+        (sign == extension && words[count..].iter().all(|&word| word == extension))
+            .then(|| words[..count].to_vec())
+    } else {
+        words.resize(count, extension);
+        Some(words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extend_words() {
+        assert_eq!(extend(&[0xff], 8, 2, true), Some(vec![u64::MAX, u64::MAX]));
+        assert_eq!(extend(&[0xff], 8, 2, false), Some(vec![0xff, 0]));
+        assert_eq!(
+            extend(&[u64::MAX, u64::MAX, u64::MAX], 192, 2, true),
+            Some(vec![u64::MAX, u64::MAX])
+        );
+        assert_eq!(extend(&[0, 0, 1], 192, 2, false), None);
+        assert_eq!(
+            extend(&[0, u64::MAX, u64::MAX], 192, 2, true),
+            Some(vec![0, u64::MAX])
+        );
+        assert_eq!(extend(&[0, 0, u64::MAX], 192, 2, true), None);
+    }
+}
@@ -0,0 +1,57 @@
+use super::{Attribute, AttributeLike};
+use crate::{ir::IntegerSet, Error};
+use mlir_sys::{mlirIntegerSetAttrGet, mlirIntegerSetAttrGetValue, MlirAttribute};
+
+/// An integer set attribute.
+#[derive(Clone, Copy)]
+pub struct IntegerSetAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> IntegerSetAttribute<'c> {
+    /// Creates an integer set attribute.
+    pub fn new(set: IntegerSet<'c>) -> Self {
+        unsafe { Self::from_raw(mlirIntegerSetAttrGet(set.to_raw())) }
+    }
+
+    /// Returns an integer set value.
+    pub fn value(&self) -> IntegerSet<'c> {
+        unsafe { IntegerSet::from_raw(mlirIntegerSetAttrGetValue(self.to_raw())) }
+    }
+}
+
+attribute_traits!(IntegerSetAttribute, is_integer_set, "integer set");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::AffineExpr, test::create_test_context};
+
+    #[test]
+    fn value() {
+        let context = create_test_context();
+        let set = IntegerSet::new(
+            &context,
+            1,
+            0,
+            &[(AffineExpr::dimension(&context, 0), false)],
+        )
+        .unwrap();
+
+        assert_eq!(IntegerSetAttribute::new(set).value(), set);
+    }
+
+    #[test]
+    fn try_from() {
+        let context = create_test_context();
+        let attribute = Attribute::parse(&context, "affine_set<(d0) : (d0 - 2 >= 0)>").unwrap();
+
+        assert_eq!(
+            IntegerSetAttribute::try_from(attribute)
+                .unwrap()
+                .value()
+                .to_string(),
+            "(d0) : (d0 - 2 >= 0)"
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use super::{Attribute, AttributeClass, AttributeLike};
+use crate::{ir::Location, Error};
+use mlir_sys::{mlirLocationFromAttribute, mlirLocationGetAttribute, MlirAttribute};
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    sync::OnceLock,
+};
+
+/// A location attribute.
+#[derive(Clone, Copy)]
+pub struct LocationAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> LocationAttribute<'c> {
+    /// Creates a location attribute.
+    pub fn new(location: Location<'c>) -> Self {
+        unsafe { Self::from_raw(mlirLocationGetAttribute(location.to_raw())) }
+    }
+
+    /// Returns a location value.
+    pub fn value(&self) -> Location<'c> {
+        unsafe { Location::from_raw(mlirLocationFromAttribute(self.to_raw())) }
+    }
+
+    unsafe fn from_raw(raw: MlirAttribute) -> Self {
+        Self {
+            attribute: Attribute::from_raw(raw),
+        }
+    }
+}
+
+// The C API provides neither a check function nor type IDs for location
+// attributes. So we compare type IDs with the ones of locations of each kind
+// instead. They are cached on the first check as type IDs are unique in a
+// process rather than in a context. Note that opaque locations cannot be
+// constructed via the C API and are not detected.
+fn is_location(attribute: Attribute) -> bool {
+    static TYPE_IDS: OnceLock<[usize; 5]> = OnceLock::new();
+
+    TYPE_IDS
+        .get_or_init(|| {
+            let context = unsafe { attribute.context().to_ref() };
+            let unknown = Location::unknown(context);
+            let file = Location::new(context, "", 0, 0);
+
+            [
+                unknown,
+                file,
+                Location::name(context, "", unknown),
+                Location::call_site(file, unknown),
+                Location::fused(
+                    context,
+                    &[file, Location::new(context, "", 1, 0)],
+                    Attribute::unit(context),
+                ),
+            ]
+            .map(|location| raw_type_id(&LocationAttribute::new(location)))
+        })
+        .contains(&raw_type_id(&attribute))
+}
+
+fn raw_type_id<'c>(attribute: &impl AttributeLike<'c>) -> usize {
+    attribute.type_id().to_raw().ptr as usize
+}
+
+impl<'c> TryFrom<Attribute<'c>> for LocationAttribute<'c> {
+    type Error = Error;
+
+    fn try_from(attribute: Attribute<'c>) -> Result<Self, Self::Error> {
+        if Self::is_instance(attribute) {
+            Ok(unsafe { Self::from_raw(attribute.to_raw()) })
+        } else {
+            Err(Error::AttributeExpected("location", attribute.to_string()))
+        }
+    }
+}
+
+impl<'c> AttributeClass<'c> for LocationAttribute<'c> {
+    fn is_instance(attribute: Attribute<'c>) -> bool {
+        is_location(attribute)
+    }
+}
+
+impl<'c> AttributeLike<'c> for LocationAttribute<'c> {
+    fn to_raw(&self) -> MlirAttribute {
+        self.attribute.to_raw()
+    }
+}
+
+impl Display for LocationAttribute<'_> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.attribute, formatter)
+    }
+}
+
+impl Debug for LocationAttribute<'_> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn value() {
+        let context = create_test_context();
+        let location = Location::new(&context, "foo", 42, 1);
+
+        assert_eq!(LocationAttribute::new(location).value(), location);
+    }
+
+    #[test]
+    fn try_from() {
+        let context = create_test_context();
+        let location = Location::name(&context, "foo", Location::unknown(&context));
+
+        assert_eq!(
+            LocationAttribute::try_from(Attribute::from(LocationAttribute::new(location)))
+                .unwrap()
+                .value(),
+            location
+        );
+    }
+
+    #[test]
+    fn try_from_in_contexts() {
+        for _ in 0..2 {
+            let context = create_test_context();
+            let location = Location::call_site(
+                Location::new(&context, "foo", 1, 2),
+                Location::unknown(&context),
+            );
+
+            assert!(
+                LocationAttribute::try_from(Attribute::from(LocationAttribute::new(location)))
+                    .is_ok()
+            );
+        }
+    }
+
+    #[test]
+    fn try_from_error() {
+        let context = create_test_context();
+
+        assert_eq!(
+            LocationAttribute::try_from(Attribute::unit(&context)).unwrap_err(),
+            Error::AttributeExpected("location", "unit".into())
+        );
+    }
+}
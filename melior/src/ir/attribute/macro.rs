@@ -14,7 +14,7 @@ macro_rules! attribute_traits {
             fn try_from(
                 attribute: crate::ir::attribute::Attribute<'c>,
             ) -> Result<Self, Self::Error> {
-                if attribute.$is_type() {
+                if <Self as crate::ir::attribute::AttributeClass>::is_instance(attribute) {
                     Ok(unsafe { Self::from_raw(attribute.to_raw()) })
                 } else {
                     Err(Error::AttributeExpected($string, attribute.to_string()))
@@ -22,6 +22,12 @@ macro_rules! attribute_traits {
             }
         }
 
+        impl<'c> crate::ir::attribute::AttributeClass<'c> for $name<'c> {
+            fn is_instance(attribute: crate::ir::attribute::Attribute<'c>) -> bool {
+                attribute.$is_type()
+            }
+        }
+
         impl<'c> crate::ir::attribute::AttributeLike<'c> for $name<'c> {
             fn to_raw(&self) -> mlir_sys::MlirAttribute {
                 self.attribute.to_raw()
@@ -0,0 +1,77 @@
+use super::{Attribute, AttributeLike};
+use crate::{ir::Type, Context, Error, StringRef};
+use mlir_sys::{
+    mlirOpaqueAttrGet, mlirOpaqueAttrGetData, mlirOpaqueAttrGetDialectNamespace, MlirAttribute,
+};
+
+/// An opaque attribute.
+///
+/// It holds attribute data of a dialect not registered in a context.
+#[derive(Clone, Copy)]
+pub struct OpaqueAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> OpaqueAttribute<'c> {
+    /// Creates an opaque attribute.
+    pub fn new(context: &'c Context, namespace: &str, data: &str, r#type: Type<'c>) -> Self {
+        unsafe {
+            Self::from_raw(mlirOpaqueAttrGet(
+                context.to_raw(),
+                StringRef::new(namespace).to_raw(),
+                data.len() as isize,
+                data.as_ptr() as *const _,
+                r#type.to_raw(),
+            ))
+        }
+    }
+
+    /// Returns a dialect namespace.
+    pub fn namespace(&self) -> &'c str {
+        unsafe { StringRef::from_raw(mlirOpaqueAttrGetDialectNamespace(self.to_raw())) }
+            .as_str()
+            .unwrap()
+    }
+
+    /// Returns data.
+    pub fn data(&self) -> &'c str {
+        unsafe { StringRef::from_raw(mlirOpaqueAttrGetData(self.to_raw())) }
+            .as_str()
+            .unwrap()
+    }
+}
+
+attribute_traits!(OpaqueAttribute, is_opaque, "opaque");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+        let attribute = OpaqueAttribute::new(&context, "foo", "bar", Type::index(&context));
+
+        assert_eq!(attribute.namespace(), "foo");
+        assert_eq!(attribute.data(), "bar");
+        assert_eq!(attribute.r#type(), Type::index(&context));
+    }
+
+    #[test]
+    fn try_from() {
+        let context = create_test_context();
+
+        assert!(
+            OpaqueAttribute::try_from(Attribute::from(OpaqueAttribute::new(
+                &context,
+                "foo",
+                "bar",
+                Type::index(&context)
+            )))
+            .is_ok()
+        );
+        assert!(OpaqueAttribute::try_from(Attribute::unit(&context)).is_err());
+    }
+}
@@ -0,0 +1,159 @@
+use super::{Attribute, AttributeLike, DenseElementsAttribute};
+use crate::{
+    ir::{Type, TypeLike},
+    Error,
+};
+use mlir_sys::{
+    mlirElementsAttrGetNumElements, mlirSparseElementsAttrGetIndices,
+    mlirSparseElementsAttrGetValues, mlirSparseElementsAttribute, MlirAttribute,
+};
+
+/// A sparse elements attribute.
+///
+/// It holds non-zero values at given indices of a shaped type.
+#[derive(Clone, Copy)]
+pub struct SparseElementsAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> SparseElementsAttribute<'c> {
+    /// Creates a sparse elements attribute.
+    ///
+    /// Indices are a 2-dimensional integer tensor of shape `[value count,
+    /// rank]` and values are a 1-dimensional tensor.
+    pub fn new(
+        r#type: Type<'c>,
+        indices: DenseElementsAttribute<'c>,
+        values: DenseElementsAttribute<'c>,
+    ) -> Result<Self, Error> {
+        if r#type.is_shaped() {
+            Ok(unsafe {
+                Self::from_raw(mlirSparseElementsAttribute(
+                    r#type.to_raw(),
+                    indices.to_raw(),
+                    values.to_raw(),
+                ))
+            })
+        } else {
+            Err(Error::TypeExpected("shaped", r#type.to_string()))
+        }
+    }
+
+    /// Returns a length.
+    pub fn len(&self) -> usize {
+        (unsafe { mlirElementsAttrGetNumElements(self.attribute.to_raw()) }) as usize
+    }
+
+    /// Checks if an array is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns indices of values.
+    pub fn indices(&self) -> DenseElementsAttribute<'c> {
+        DenseElementsAttribute::try_from(unsafe {
+            Attribute::from_raw(mlirSparseElementsAttrGetIndices(self.to_raw()))
+        })
+        .expect("dense elements attribute")
+    }
+
+    /// Returns values.
+    pub fn values(&self) -> DenseElementsAttribute<'c> {
+        DenseElementsAttribute::try_from(unsafe {
+            Attribute::from_raw(mlirSparseElementsAttrGetValues(self.to_raw()))
+        })
+        .expect("dense elements attribute")
+    }
+}
+
+attribute_traits!(
+    SparseElementsAttribute,
+    is_sparse_elements,
+    "sparse elements"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{
+            attribute::IntegerAttribute,
+            r#type::{IntegerType, RankedTensorType},
+        },
+        test::create_test_context,
+        Context,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn create_attribute(context: &Context) -> SparseElementsAttribute {
+        let index_type = IntegerType::new(context, 64).into();
+        let value_type = IntegerType::new(context, 32).into();
+
+        SparseElementsAttribute::new(
+            RankedTensorType::new(&[3, 4], value_type, None).into(),
+            DenseElementsAttribute::new(
+                RankedTensorType::new(&[2, 2], index_type, None).into(),
+                &[0, 0, 1, 2].map(|index| IntegerAttribute::new(index_type, index).into()),
+            )
+            .unwrap(),
+            DenseElementsAttribute::new(
+                RankedTensorType::new(&[2], value_type, None).into(),
+                &[1, 5].map(|value| IntegerAttribute::new(value_type, value).into()),
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            create_attribute(&context).to_string(),
+            "sparse<[[0, 0], [1, 2]], [1, 5]> : tensor<3x4xi32>"
+        );
+    }
+
+    #[test]
+    fn new_error() {
+        let context = create_test_context();
+        let r#type = Type::index(&context);
+        let attribute = create_attribute(&context);
+
+        assert_eq!(
+            SparseElementsAttribute::new(r#type, attribute.indices(), attribute.values())
+                .unwrap_err(),
+            Error::TypeExpected("shaped", r#type.to_string())
+        );
+    }
+
+    #[test]
+    fn len() {
+        let context = create_test_context();
+
+        assert_eq!(create_attribute(&context).len(), 12);
+    }
+
+    #[test]
+    fn indices_and_values() {
+        let context = create_test_context();
+        let attribute = create_attribute(&context);
+
+        assert_eq!(attribute.indices().i64_element(3), Ok(2));
+        assert_eq!(attribute.values().i32_element(1), Ok(5));
+    }
+
+    #[test]
+    fn try_from() {
+        let context = create_test_context();
+
+        assert!(
+            SparseElementsAttribute::try_from(Attribute::from(create_attribute(&context))).is_ok()
+        );
+        assert!(SparseElementsAttribute::try_from(
+            Attribute::parse(&context, "dense<1> : tensor<2xi32>").unwrap()
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use super::{Attribute, AttributeLike};
+use crate::{Context, Error};
+use mlir_sys::{
+    mlirStridedLayoutAttrGet, mlirStridedLayoutAttrGetNumStrides, mlirStridedLayoutAttrGetOffset,
+    mlirStridedLayoutAttrGetStride, MlirAttribute,
+};
+
+/// A strided layout attribute.
+///
+/// Dynamic offsets and strides are represented by `i64::MIN`.
+#[derive(Clone, Copy)]
+pub struct StridedLayoutAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> StridedLayoutAttribute<'c> {
+    /// Creates a strided layout attribute.
+    pub fn new(context: &'c Context, offset: i64, strides: &[i64]) -> Self {
+        unsafe {
+            Self::from_raw(mlirStridedLayoutAttrGet(
+                context.to_raw(),
+                offset,
+                strides.len() as isize,
+                strides.as_ptr(),
+            ))
+        }
+    }
+
+    /// Returns an offset.
+    pub fn offset(&self) -> i64 {
+        unsafe { mlirStridedLayoutAttrGetOffset(self.to_raw()) }
+    }
+
+    /// Returns a number of strides.
+    pub fn stride_count(&self) -> usize {
+        (unsafe { mlirStridedLayoutAttrGetNumStrides(self.to_raw()) }) as usize
+    }
+
+    /// Returns a stride.
+    pub fn stride(&self, index: usize) -> Result<i64, Error> {
+        if index < self.stride_count() {
+            Ok(unsafe { mlirStridedLayoutAttrGetStride(self.to_raw(), index as isize) })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "stride",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns all strides.
+    pub fn strides(&self) -> impl Iterator<Item = i64> + '_ {
+        (0..self.stride_count()).map(|index| self.stride(index).expect("valid stride index"))
+    }
+}
+
+attribute_traits!(StridedLayoutAttribute, is_strided_layout, "strided layout");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            StridedLayoutAttribute::new(&context, 4, &[8, 1]).to_string(),
+            "strided<[8, 1], offset: 4>"
+        );
+    }
+
+    #[test]
+    fn offset() {
+        let context = create_test_context();
+
+        assert_eq!(StridedLayoutAttribute::new(&context, 4, &[1]).offset(), 4);
+    }
+
+    #[test]
+    fn stride() {
+        let context = create_test_context();
+        let attribute = StridedLayoutAttribute::new(&context, 0, &[8, i64::MIN]);
+
+        assert_eq!(attribute.stride_count(), 2);
+        assert_eq!(attribute.stride(0), Ok(8));
+        assert_eq!(attribute.stride(1), Ok(i64::MIN));
+        assert!(matches!(
+            attribute.stride(2),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+        assert_eq!(attribute.strides().collect::<Vec<_>>(), vec![8, i64::MIN]);
+        assert_eq!(attribute.to_string(), "strided<[8, ?]>");
+    }
+
+    #[test]
+    fn try_from() {
+        let context = create_test_context();
+
+        assert!(StridedLayoutAttribute::try_from(
+            Attribute::parse(&context, "strided<[2, 1], offset: ?>").unwrap()
+        )
+        .is_ok());
+        assert!(StridedLayoutAttribute::try_from(Attribute::unit(&context)).is_err());
+    }
+}
@@ -0,0 +1,152 @@
+use super::{Attribute, AttributeLike, FlatSymbolRefAttribute};
+use crate::{Context, Error, StringRef};
+use mlir_sys::{
+    mlirSymbolRefAttrGet, mlirSymbolRefAttrGetLeafReference, mlirSymbolRefAttrGetNestedReference,
+    mlirSymbolRefAttrGetNumNestedReferences, mlirSymbolRefAttrGetRootReference, MlirAttribute,
+};
+
+/// A symbol ref attribute.
+///
+/// It refers to a symbol with a root reference and nested references, as in
+/// `@foo::@bar::@baz`.
+#[derive(Clone, Copy)]
+pub struct SymbolRefAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> SymbolRefAttribute<'c> {
+    /// Creates a symbol ref attribute.
+    pub fn new(context: &'c Context, root: &str, nested: &[&str]) -> Self {
+        let nested = nested
+            .iter()
+            .map(|symbol| FlatSymbolRefAttribute::new(context, symbol).to_raw())
+            .collect::<Vec<_>>();
+
+        unsafe {
+            Self::from_raw(mlirSymbolRefAttrGet(
+                context.to_raw(),
+                StringRef::new(root).to_raw(),
+                nested.len() as isize,
+                nested.as_ptr(),
+            ))
+        }
+    }
+
+    /// Returns a root reference.
+    pub fn root_reference(&self) -> &'c str {
+        unsafe { StringRef::from_raw(mlirSymbolRefAttrGetRootReference(self.to_raw())) }
+            .as_str()
+            .unwrap()
+    }
+
+    /// Returns a leaf reference.
+    ///
+    /// It is the root reference if there are no nested references.
+    pub fn leaf_reference(&self) -> &'c str {
+        unsafe { StringRef::from_raw(mlirSymbolRefAttrGetLeafReference(self.to_raw())) }
+            .as_str()
+            .unwrap()
+    }
+
+    /// Returns a number of nested references.
+    pub fn nested_reference_count(&self) -> usize {
+        (unsafe { mlirSymbolRefAttrGetNumNestedReferences(self.to_raw()) }) as usize
+    }
+
+    /// Returns a nested reference.
+    pub fn nested_reference(&self, index: usize) -> Result<FlatSymbolRefAttribute<'c>, Error> {
+        if index < self.nested_reference_count() {
+            FlatSymbolRefAttribute::try_from(unsafe {
+                Attribute::from_raw(mlirSymbolRefAttrGetNestedReference(
+                    self.to_raw(),
+                    index as isize,
+                ))
+            })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "nested reference",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns all nested references.
+    pub fn nested_references(&self) -> impl Iterator<Item = FlatSymbolRefAttribute<'c>> + '_ {
+        (0..self.nested_reference_count()).map(|index| {
+            self.nested_reference(index)
+                .expect("valid nested reference index")
+        })
+    }
+}
+
+attribute_traits!(SymbolRefAttribute, is_symbol_ref, "symbol ref");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            SymbolRefAttribute::new(&context, "foo", &["bar", "baz"]).to_string(),
+            "@foo::@bar::@baz"
+        );
+    }
+
+    #[test]
+    fn root_reference() {
+        let context = create_test_context();
+        let attribute = SymbolRefAttribute::new(&context, "foo", &["bar", "baz"]);
+
+        assert_eq!(attribute.root_reference(), "foo");
+    }
+
+    #[test]
+    fn leaf_reference() {
+        let context = create_test_context();
+
+        assert_eq!(
+            SymbolRefAttribute::new(&context, "foo", &["bar", "baz"]).leaf_reference(),
+            "baz"
+        );
+        assert_eq!(
+            SymbolRefAttribute::new(&context, "foo", &[]).leaf_reference(),
+            "foo"
+        );
+    }
+
+    #[test]
+    fn nested_reference() {
+        let context = create_test_context();
+        let attribute = SymbolRefAttribute::new(&context, "foo", &["bar", "baz"]);
+
+        assert_eq!(attribute.nested_reference_count(), 2);
+        assert_eq!(attribute.nested_reference(1).unwrap().value(), "baz");
+        assert!(matches!(
+            attribute.nested_reference(2),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+        assert_eq!(
+            attribute
+                .nested_references()
+                .map(|reference| reference.value())
+                .collect::<Vec<_>>(),
+            vec!["bar", "baz"]
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let context = create_test_context();
+
+        assert!(
+            SymbolRefAttribute::try_from(Attribute::parse(&context, "@foo::@bar").unwrap()).is_ok()
+        );
+        assert!(SymbolRefAttribute::try_from(Attribute::unit(&context)).is_err());
+    }
+}
@@ -0,0 +1,42 @@
+use super::{Attribute, AttributeLike};
+use crate::{Context, Error};
+use mlir_sys::{mlirUnitAttrGet, MlirAttribute};
+
+/// A unit attribute.
+#[derive(Clone, Copy)]
+pub struct UnitAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> UnitAttribute<'c> {
+    /// Creates a unit attribute.
+    pub fn new(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirUnitAttrGet(context.to_raw())) }
+    }
+}
+
+attribute_traits!(UnitAttribute, is_unit, "unit");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Attribute::from(UnitAttribute::new(&context)),
+            Attribute::unit(&context)
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let context = create_test_context();
+
+        assert!(UnitAttribute::try_from(Attribute::unit(&context)).is_ok());
+        assert!(UnitAttribute::try_from(Attribute::parse(&context, "42 : i64").unwrap()).is_err());
+    }
+}
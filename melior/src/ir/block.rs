@@ -2,9 +2,11 @@
 
 mod argument;
 mod block_like;
+mod printer;
 
 pub use self::argument::BlockArgument;
 pub use self::block_like::BlockLike;
+pub(crate) use self::printer::print_region_with_flags;
 use super::{Location, Type, TypeLike, Value};
 use crate::{context::Context, utility::print_callback};
 use mlir_sys::{
@@ -206,11 +208,14 @@ mod tests {
     use super::*;
     use crate::{
         ir::{
-            operation::OperationBuilder, r#type::IntegerType, Module, Region, RegionLike, ValueLike,
+            operation::{OperationBuilder, OperationPrintingFlags},
+            r#type::IntegerType,
+            Module, Region, RegionLike, Type, ValueLike,
         },
         test::create_test_context,
         Error,
     };
+    use indoc::indoc;
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -218,6 +223,104 @@ mod tests {
         Block::new(&[]);
     }
 
+    #[test]
+    fn print_with_flags() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo(%arg0 : i32) -> i32 {
+                  %0 = arith.addi %arg0, %arg0 : i32
+                  return %0 : i32
+                }
+                "
+            ),
+        )
+        .unwrap();
+        let mut string = String::new();
+
+        module
+            .body()
+            .first_operation()
+            .unwrap()
+            .region(0)
+            .unwrap()
+            .first_block()
+            .unwrap()
+            .print_with_flags(&mut string, OperationPrintingFlags::new())
+            .unwrap();
+
+        assert!(string.starts_with(indoc!(
+            "
+            ^bb0(%arg0: i32):
+              %0 = arith.addi %arg0, %arg0 : i32
+            "
+        )));
+        assert_eq!(string.lines().count(), 3);
+    }
+
+    #[test]
+    fn print_with_flags_debug_info() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo(%arg0 : i32) -> i32 {
+                  return %arg0 : i32
+                }
+                "
+            ),
+        )
+        .unwrap();
+        let mut string = String::new();
+
+        module
+            .body()
+            .first_operation()
+            .unwrap()
+            .region(0)
+            .unwrap()
+            .first_block()
+            .unwrap()
+            .print_with_flags(
+                &mut string,
+                OperationPrintingFlags::new().enable_debug_info(true, false),
+            )
+            .unwrap();
+
+        let mut lines = string.lines();
+
+        assert_eq!(lines.next(), Some("^bb0(%arg0: i32):"));
+        assert!(lines.next().unwrap().contains(" loc("));
+    }
+
+    #[test]
+    fn print_with_flags_unlinked() {
+        let mut string = String::new();
+
+        Block::new(&[])
+            .print_with_flags(&mut string, OperationPrintingFlags::new())
+            .unwrap();
+
+        assert_eq!(string, "<<UNLINKED BLOCK>>\n");
+    }
+
+    #[test]
+    fn print_as_operand() {
+        let region = Region::new();
+        let first_block = region.append_block(Block::new(&[]));
+        let second_block = region.append_block(Block::new(&[]));
+        let mut string = String::new();
+
+        first_block.print_as_operand(&mut string).unwrap();
+        second_block.print_as_operand(&mut string).unwrap();
+        Block::new(&[]).print_as_operand(&mut string).unwrap();
+
+        assert_eq!(string, "^bb0^bb1<<UNKNOWN BLOCK>>");
+    }
+
     #[test]
     fn argument() {
         let context = create_test_context();
@@ -249,6 +352,83 @@ mod tests {
         assert_eq!(Block::new(&[]).argument_count(), 0);
     }
 
+    #[test]
+    fn insert_argument() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let index_type = Type::index(&context);
+        let integer_type = IntegerType::new(&context, 64).into();
+
+        let block = Block::new(&[(index_type, location)]);
+        let argument = block.insert_argument(0, integer_type, location).unwrap();
+
+        assert_eq!(argument.r#type(), integer_type);
+        assert_eq!(block.argument_count(), 2);
+        assert_eq!(block.argument(0).unwrap().r#type(), integer_type);
+        assert_eq!(block.argument(1).unwrap().r#type(), index_type);
+    }
+
+    #[test]
+    fn insert_argument_error() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Block::new(&[])
+                .insert_argument(1, Type::index(&context), Location::unknown(&context))
+                .unwrap_err(),
+            Error::PositionOutOfBounds {
+                name: "block argument",
+                value: "<<UNLINKED BLOCK>>\n".into(),
+                index: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn erase_argument() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let index_type = Type::index(&context);
+        let integer_type = IntegerType::new(&context, 64).into();
+
+        let block = Block::new(&[(index_type, location), (integer_type, location)]);
+
+        block.erase_argument(0).unwrap();
+
+        assert_eq!(block.argument_count(), 1);
+        assert_eq!(block.argument(0).unwrap().r#type(), integer_type);
+    }
+
+    #[test]
+    fn erase_argument_error() {
+        assert_eq!(
+            Block::new(&[]).erase_argument(0).unwrap_err(),
+            Error::PositionOutOfBounds {
+                name: "block argument",
+                value: "<<UNLINKED BLOCK>>\n".into(),
+                index: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn erase_argument_in_use() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+
+        let block = Block::new(&[(Type::index(&context), location)]);
+        block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[block.argument(0).unwrap().into()])
+                .build()
+                .unwrap(),
+        );
+
+        assert!(matches!(block.erase_argument(0), Err(Error::ValueInUse(_))));
+        assert_eq!(block.argument_count(), 1);
+    }
+
     #[test]
     fn parent_region() {
         let region = Region::new();
@@ -14,14 +14,14 @@ pub struct BlockArgument<'c, 'a> {
     value: Value<'c, 'a>,
 }
 
-impl<'c> BlockArgument<'c, '_> {
+impl<'c, 'a> BlockArgument<'c, 'a> {
     /// Returns an argument number.
     pub fn argument_number(&self) -> usize {
         unsafe { mlirBlockArgumentGetArgNumber(self.value.to_raw()) as usize }
     }
 
     /// Returns an owner operation.
-    pub fn owner(&self) -> BlockRef<'c, '_> {
+    pub fn owner(&self) -> BlockRef<'c, 'a> {
         unsafe { BlockRef::from_raw(mlirBlockArgumentGetOwner(self.value.to_raw())) }
     }
 
@@ -1,15 +1,21 @@
-use super::{BlockArgument, BlockRef, TypeLike};
+use super::{printer::print_block_with_flags, BlockArgument, BlockRef, TypeLike};
 use crate::{
-    ir::{operation::OperationRefMut, Location, Operation, OperationRef, RegionRef, Type, Value},
+    ir::{
+        operation::{OperationPrintingFlags, OperationRefMut},
+        Location, Operation, OperationRef, RegionLike, RegionRef, Type, Value, ValueLike,
+    },
     Error,
 };
-use core::fmt::Display;
+use core::{
+    fmt::{self, Display},
+    iter::successors,
+};
 use mlir_sys::{
-    mlirBlockAddArgument, mlirBlockAppendOwnedOperation, mlirBlockGetArgument,
-    mlirBlockGetFirstOperation, mlirBlockGetNextInRegion, mlirBlockGetNumArguments,
-    mlirBlockGetParentOperation, mlirBlockGetParentRegion, mlirBlockGetTerminator,
-    mlirBlockInsertOwnedOperation, mlirBlockInsertOwnedOperationAfter,
-    mlirBlockInsertOwnedOperationBefore, MlirBlock,
+    mlirBlockAddArgument, mlirBlockAppendOwnedOperation, mlirBlockEraseArgument,
+    mlirBlockGetArgument, mlirBlockGetFirstOperation, mlirBlockGetNextInRegion,
+    mlirBlockGetNumArguments, mlirBlockGetParentOperation, mlirBlockGetParentRegion,
+    mlirBlockGetTerminator, mlirBlockInsertArgument, mlirBlockInsertOwnedOperation,
+    mlirBlockInsertOwnedOperationAfter, mlirBlockInsertOwnedOperationBefore, MlirBlock,
 };
 
 /// A trait for block-like types.
@@ -83,6 +89,46 @@ pub trait BlockLike<'c, 'a>: Copy + Display {
         }
     }
 
+    /// Inserts an argument at a position.
+    fn insert_argument(
+        self,
+        index: usize,
+        r#type: Type<'c>,
+        location: Location<'c>,
+    ) -> Result<Value<'c, 'a>, Error> {
+        if index <= self.argument_count() {
+            Ok(unsafe {
+                Value::from_raw(mlirBlockInsertArgument(
+                    self.to_raw(),
+                    index as isize,
+                    r#type.to_raw(),
+                    location.to_raw(),
+                ))
+            })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "block argument",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Erases an argument at a position.
+    ///
+    /// The argument must not have any uses.
+    fn erase_argument(self, index: usize) -> Result<(), Error> {
+        let argument = self.argument(index)?;
+
+        if argument.uses().next().is_some() {
+            return Err(Error::ValueInUse(argument.to_string()));
+        }
+
+        unsafe { mlirBlockEraseArgument(self.to_raw(), index as u32) }
+
+        Ok(())
+    }
+
     /// Appends an operation.
     fn append_operation(self, operation: Operation<'c>) -> OperationRef<'c, 'a> {
         unsafe {
@@ -141,4 +187,44 @@ pub trait BlockLike<'c, 'a>: Copy + Display {
     fn next_in_region(self) -> Option<BlockRef<'c, 'a>> {
         unsafe { BlockRef::from_option_raw(mlirBlockGetNextInRegion(self.to_raw())) }
     }
+
+    /// Prints a block as an operand, such as `^bb1`, into a writer.
+    ///
+    /// Unlike [`ValueLike::print_as_operand`](crate::ir::ValueLike::print_as_operand),
+    /// it does not take an [`AsmState`](crate::ir::operation::AsmState) because
+    /// the C API provides neither a function to print blocks as operands nor a
+    /// way to look up block names in assembly states. So a block is named after
+    /// its position in a parent region as in the default printer. Therefore,
+    /// custom block names given by operations are not reflected, and it takes
+    /// time linear in the number of preceding blocks in the region.
+    fn print_as_operand(self, writer: &mut impl fmt::Write) -> fmt::Result {
+        let Some(region) = self.parent_region() else {
+            return write!(writer, "<<UNKNOWN BLOCK>>");
+        };
+        let index = successors(region.first_block(), |block| block.next_in_region())
+            .position(|block| block.to_raw().ptr == self.to_raw().ptr)
+            .expect("block in parent region");
+
+        write!(writer, "^bb{index}")
+    }
+
+    /// Prints a block with flags into a writer.
+    ///
+    /// The C API does not support printing blocks with flags. So a block header
+    /// and operations in the block are printed separately with an assembly
+    /// state of its top-level operation.
+    ///
+    /// All the flags apply to the operations. In the block header, argument
+    /// names follow the assembly state but argument types are printed without
+    /// the flags. Argument locations and users are never printed even with
+    /// [`enable_debug_info`](OperationPrintingFlags::enable_debug_info) or
+    /// [`print_value_users`](OperationPrintingFlags::print_value_users)
+    /// because the C API provides no way to get them.
+    fn print_with_flags(
+        self,
+        writer: &mut impl fmt::Write,
+        flags: OperationPrintingFlags,
+    ) -> fmt::Result {
+        print_block_with_flags(unsafe { BlockRef::from_raw(self.to_raw()) }, writer, flags)
+    }
 }
@@ -0,0 +1,122 @@
+use super::{BlockLike, BlockRef};
+use crate::ir::{
+    operation::{AsmState, OperationPrintingFlags},
+    OperationRef, ValueLike,
+};
+use mlir_sys::mlirOperationGetNextInBlock;
+use std::fmt::{self, Write};
+
+const INDENT: &str = "  ";
+
+/// Prints a block with an assembly state of its top-level operation.
+pub(crate) fn print_block_with_flags(
+    block: BlockRef,
+    writer: &mut impl Write,
+    flags: OperationPrintingFlags,
+) -> fmt::Result {
+    let Some(operation) = block.parent_operation() else {
+        return writeln!(writer, "<<UNLINKED BLOCK>>");
+    };
+    let operation = top_level_operation(operation);
+    let state = AsmState::for_operation(&operation, flags);
+
+    print_block(block, writer, &state)
+}
+
+/// Prints blocks in a region with an assembly state of its top-level
+/// operation.
+pub(crate) fn print_region_with_flags(
+    block: Option<BlockRef>,
+    writer: &mut impl Write,
+    flags: OperationPrintingFlags,
+) -> fmt::Result {
+    writeln!(writer, "{{")?;
+
+    if let Some(block) = block {
+        let Some(operation) = block.parent_operation() else {
+            return writeln!(writer, "<<UNLINKED REGION>>\n}}");
+        };
+        let operation = top_level_operation(operation);
+        let state = AsmState::for_operation(&operation, flags);
+        let mut block = Some(block);
+
+        while let Some(current) = block {
+            print_block(current, writer, &state)?;
+            block = current.next_in_region();
+        }
+    }
+
+    writeln!(writer, "}}")
+}
+
+fn print_block<'c>(
+    block: BlockRef<'c, '_>,
+    writer: &mut impl Write,
+    state: &AsmState<'c, '_>,
+) -> fmt::Result {
+    block.print_as_operand(writer)?;
+
+    if block.argument_count() > 0 {
+        write!(writer, "(")?;
+
+        for index in 0..block.argument_count() {
+            let argument = block.argument(index).expect("valid argument index");
+
+            if index > 0 {
+                write!(writer, ", ")?;
+            }
+
+            argument.print_as_operand(writer, state)?;
+            write!(writer, ": {}", argument.r#type())?;
+        }
+
+        write!(writer, ")")?;
+    }
+
+    writeln!(writer, ":")?;
+
+    let mut writer = IndentedWriter {
+        writer,
+        line_start: true,
+    };
+    let mut operation = block.first_operation();
+
+    while let Some(current) = operation {
+        current.print_with_state(&mut writer, state)?;
+        writeln!(writer)?;
+
+        operation =
+            unsafe { OperationRef::from_option_raw(mlirOperationGetNextInBlock(current.to_raw())) };
+    }
+
+    Ok(())
+}
+
+fn top_level_operation<'c, 'a>(mut operation: OperationRef<'c, 'a>) -> OperationRef<'c, 'a> {
+    while let Some(parent) = operation.parent_operation() {
+        operation = unsafe { OperationRef::from_raw(parent.to_raw()) };
+    }
+
+    operation
+}
+
+// Indents lines written into an inner writer.
+struct IndentedWriter<'a, W> {
+    writer: &'a mut W,
+    line_start: bool,
+}
+
+impl<W: Write> Write for IndentedWriter<'_, W> {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        for line in string.split_inclusive('\n') {
+            if self.line_start && line != "\n" {
+                self.writer.write_str(INDENT)?;
+            }
+
+            self.writer.write_str(line)?;
+            self.line_start = line.ends_with('\n');
+        }
+
+        Ok(())
+    }
+}
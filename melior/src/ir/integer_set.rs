@@ -0,0 +1,329 @@
+use super::AffineExpr;
+use crate::{
+    context::{Context, ContextRef},
+    utility::print_callback,
+    Error,
+};
+use mlir_sys::{
+    mlirIntegerSetDump, mlirIntegerSetEmptyGet, mlirIntegerSetEqual, mlirIntegerSetGet,
+    mlirIntegerSetGetConstraint, mlirIntegerSetGetContext, mlirIntegerSetGetNumConstraints,
+    mlirIntegerSetGetNumDims, mlirIntegerSetGetNumEqualities, mlirIntegerSetGetNumInequalities,
+    mlirIntegerSetGetNumInputs, mlirIntegerSetGetNumSymbols, mlirIntegerSetIsCanonicalEmpty,
+    mlirIntegerSetIsConstraintEq, mlirIntegerSetPrint, mlirIntegerSetReplaceGet, MlirIntegerSet,
+};
+use std::{
+    ffi::c_void,
+    fmt::{self, Debug, Display, Formatter},
+    marker::PhantomData,
+};
+
+/// An integer set.
+///
+/// It is a set of points satisfying affine constraints, each of which is either
+/// an equality `expression == 0` or an inequality `expression >= 0`.
+#[derive(Clone, Copy)]
+pub struct IntegerSet<'c> {
+    raw: MlirIntegerSet,
+    _context: PhantomData<&'c Context>,
+}
+
+impl<'c> IntegerSet<'c> {
+    /// Creates an integer set.
+    ///
+    /// Each constraint is paired with a flag which is `true` for an equality
+    /// and `false` for an inequality. It returns an error if no constraint is
+    /// given. Use [`IntegerSet::empty`] for a set without any points.
+    pub fn new(
+        context: &'c Context,
+        dimension_count: usize,
+        symbol_count: usize,
+        constraints: &[(AffineExpr<'c>, bool)],
+    ) -> Result<Self, Error> {
+        if constraints.is_empty() {
+            return Err(Error::ConstraintExpected);
+        }
+
+        let (expressions, flags): (Vec<_>, Vec<_>) = constraints
+            .iter()
+            .map(|(expression, equality)| (expression.to_raw(), *equality))
+            .unzip();
+
+        Ok(unsafe {
+            Self::from_raw(mlirIntegerSetGet(
+                context.to_raw(),
+                dimension_count as isize,
+                symbol_count as isize,
+                expressions.len() as isize,
+                expressions.as_ptr(),
+                flags.as_ptr(),
+            ))
+        })
+    }
+
+    /// Creates a canonical empty integer set.
+    pub fn empty(context: &'c Context, dimension_count: usize, symbol_count: usize) -> Self {
+        unsafe {
+            Self::from_raw(mlirIntegerSetEmptyGet(
+                context.to_raw(),
+                dimension_count as isize,
+                symbol_count as isize,
+            ))
+        }
+    }
+
+    /// Returns a context.
+    pub fn context(&self) -> ContextRef<'c> {
+        unsafe { ContextRef::from_raw(mlirIntegerSetGetContext(self.raw)) }
+    }
+
+    /// Returns a number of dimensions.
+    pub fn dimension_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumDims(self.raw) as usize }
+    }
+
+    /// Returns a number of symbols.
+    pub fn symbol_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumSymbols(self.raw) as usize }
+    }
+
+    /// Returns a number of inputs, which are dimensions and symbols.
+    pub fn input_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumInputs(self.raw) as usize }
+    }
+
+    /// Returns a number of constraints.
+    pub fn constraint_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumConstraints(self.raw) as usize }
+    }
+
+    /// Returns a number of equality constraints.
+    pub fn equality_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumEqualities(self.raw) as usize }
+    }
+
+    /// Returns a number of inequality constraints.
+    pub fn inequality_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumInequalities(self.raw) as usize }
+    }
+
+    /// Returns a constraint at a position with its equality flag.
+    pub fn constraint(&self, index: usize) -> Option<(AffineExpr<'c>, bool)> {
+        (index < self.constraint_count()).then(|| unsafe { self.constraint_unchecked(index) })
+    }
+
+    /// Returns constraints with their equality flags.
+    pub fn constraints(&self) -> impl Iterator<Item = (AffineExpr<'c>, bool)> + '_ {
+        (0..self.constraint_count()).map(|index| unsafe { self.constraint_unchecked(index) })
+    }
+
+    unsafe fn constraint_unchecked(&self, index: usize) -> (AffineExpr<'c>, bool) {
+        (
+            AffineExpr::from_raw(mlirIntegerSetGetConstraint(self.raw, index as isize)),
+            mlirIntegerSetIsConstraintEq(self.raw, index as isize),
+        )
+    }
+
+    /// Replaces dimensions and symbols in an integer set.
+    ///
+    /// Replacements must cover all dimensions and symbols of the set.
+    pub fn replace(
+        &self,
+        dimensions: &[AffineExpr<'c>],
+        symbols: &[AffineExpr<'c>],
+        dimension_count: usize,
+        symbol_count: usize,
+    ) -> Option<Self> {
+        if dimensions.len() != self.dimension_count() || symbols.len() != self.symbol_count() {
+            return None;
+        }
+
+        let dimensions = dimensions
+            .iter()
+            .map(|expression| expression.to_raw())
+            .collect::<Vec<_>>();
+        let symbols = symbols
+            .iter()
+            .map(|expression| expression.to_raw())
+            .collect::<Vec<_>>();
+
+        Some(unsafe {
+            Self::from_raw(mlirIntegerSetReplaceGet(
+                self.raw,
+                dimensions.as_ptr(),
+                symbols.as_ptr(),
+                dimension_count as isize,
+                symbol_count as isize,
+            ))
+        })
+    }
+
+    /// Returns `true` if an integer set is a canonical empty set.
+    pub fn is_canonical_empty(&self) -> bool {
+        unsafe { mlirIntegerSetIsCanonicalEmpty(self.raw) }
+    }
+
+    /// Dumps an integer set.
+    pub fn dump(&self) {
+        unsafe { mlirIntegerSetDump(self.raw) }
+    }
+
+    /// Creates an integer set from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirIntegerSet) -> Self {
+        Self {
+            raw,
+            _context: Default::default(),
+        }
+    }
+
+    /// Converts an integer set into a raw object.
+    pub const fn to_raw(self) -> MlirIntegerSet {
+        self.raw
+    }
+}
+
+impl PartialEq for IntegerSet<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { mlirIntegerSetEqual(self.raw, other.raw) }
+    }
+}
+
+impl Eq for IntegerSet<'_> {}
+
+impl Display for IntegerSet<'_> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        let mut data = (formatter, Ok(()));
+
+        unsafe {
+            mlirIntegerSetPrint(
+                self.raw,
+                Some(print_callback),
+                &mut data as *mut _ as *mut c_void,
+            );
+        }
+
+        data.1
+    }
+}
+
+impl Debug for IntegerSet<'_> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+    use pretty_assertions::assert_eq;
+
+    fn create_set(context: &Context) -> IntegerSet {
+        let dimension = AffineExpr::dimension(context, 0);
+        let symbol = AffineExpr::symbol(context, 0);
+
+        IntegerSet::new(
+            context,
+            1,
+            1,
+            &[
+                (dimension - AffineExpr::constant(context, 2), false),
+                (dimension - symbol, true),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            create_set(&context).to_string(),
+            "(d0)[s0] : (d0 - 2 >= 0, d0 - s0 == 0)"
+        );
+    }
+
+    #[test]
+    fn new_without_constraints() {
+        let context = create_test_context();
+
+        assert_eq!(
+            IntegerSet::new(&context, 1, 0, &[]),
+            Err(Error::ConstraintExpected)
+        );
+    }
+
+    #[test]
+    fn counts() {
+        let context = create_test_context();
+        let set = create_set(&context);
+
+        assert_eq!(set.dimension_count(), 1);
+        assert_eq!(set.symbol_count(), 1);
+        assert_eq!(set.input_count(), 2);
+        assert_eq!(set.constraint_count(), 2);
+        assert_eq!(set.equality_count(), 1);
+        assert_eq!(set.inequality_count(), 1);
+    }
+
+    #[test]
+    fn constraint() {
+        let context = create_test_context();
+        let set = create_set(&context);
+
+        assert_eq!(
+            set.constraint(1),
+            Some((
+                AffineExpr::dimension(&context, 0) - AffineExpr::symbol(&context, 0),
+                true
+            ))
+        );
+        assert_eq!(set.constraint(2), None);
+        assert_eq!(
+            set.constraints()
+                .map(|(_, equality)| equality)
+                .collect::<Vec<_>>(),
+            vec![false, true]
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let context = create_test_context();
+        let set = IntegerSet::empty(&context, 1, 0);
+
+        assert!(set.is_canonical_empty());
+        assert!(!create_set(&context).is_canonical_empty());
+    }
+
+    #[test]
+    fn replace() {
+        let context = create_test_context();
+        let set = create_set(&context);
+
+        assert_eq!(
+            set.replace(
+                &[AffineExpr::dimension(&context, 1)],
+                &[AffineExpr::constant(&context, 4)],
+                2,
+                0
+            )
+            .unwrap()
+            .to_string(),
+            "(d0, d1) : (d1 - 2 >= 0, d1 - 4 == 0)"
+        );
+        assert_eq!(set.replace(&[], &[], 0, 0), None);
+    }
+
+    #[test]
+    fn equal() {
+        let context = create_test_context();
+
+        assert_eq!(create_set(&context), create_set(&context));
+        assert_ne!(create_set(&context), IntegerSet::empty(&context, 1, 1));
+    }
+}
@@ -1,18 +1,25 @@
+mod kind;
+mod owned;
+mod parser;
+
+pub use self::{kind::LocationKind, owned::OwnedLocation};
 use crate::{
     context::{Context, ContextRef},
     ir::{Attribute, AttributeLike},
     string_ref::StringRef,
     utility::print_callback,
+    Error,
 };
 use mlir_sys::{
-    mlirLocationCallSiteGet, mlirLocationEqual, mlirLocationFileLineColGet, mlirLocationFusedGet,
-    mlirLocationGetContext, mlirLocationNameGet, mlirLocationPrint, mlirLocationUnknownGet,
-    MlirLocation,
+    mlirEmitError, mlirLocationCallSiteGet, mlirLocationEqual, mlirLocationFileLineColGet,
+    mlirLocationFusedGet, mlirLocationGetContext, mlirLocationNameGet, mlirLocationPrint,
+    mlirLocationUnknownGet, MlirLocation,
 };
 use std::{
-    ffi::c_void,
+    ffi::{c_void, CString},
     fmt::{self, Display, Formatter},
     marker::PhantomData,
+    panic,
 };
 
 /// A location
@@ -68,11 +75,103 @@ impl<'c> Location<'c> {
         unsafe { Self::from_raw(mlirLocationUnknownGet(context.to_raw())) }
     }
 
+    /// Creates a location of a caller in Rust source code.
+    #[track_caller]
+    pub fn caller(context: &'c Context) -> Self {
+        let location = panic::Location::caller();
+
+        Self::new(
+            context,
+            location.file(),
+            location.line() as usize,
+            location.column() as usize,
+        )
+    }
+
+    /// Attaches a location of a caller in Rust source code.
+    ///
+    /// It returns the caller location if a location is unknown, or a fused
+    /// location of both otherwise.
+    #[track_caller]
+    pub fn with_caller(self) -> Self {
+        let context = unsafe { self.context().to_ref() };
+        let caller = Self::caller(context);
+
+        if self == Self::unknown(context) {
+            caller
+        } else {
+            Self::fused(context, &[self, caller], unsafe { Attribute::null() })
+        }
+    }
+
+    /// Emits an error diagnostic at a location.
+    ///
+    /// It returns an error if a message contains a nul byte. Warnings, remarks,
+    /// and notes cannot be emitted as the C API provides no function for them.
+    pub fn emit_error(&self, message: &str) -> Result<(), Error> {
+        let message = CString::new(message)?;
+
+        unsafe { mlirEmitError(self.raw, message.as_ptr()) }
+
+        Ok(())
+    }
+
     /// Returns a context.
     pub fn context(&self) -> ContextRef<'c> {
         unsafe { ContextRef::from_raw(mlirLocationGetContext(self.raw)) }
     }
 
+    /// Returns a kind with components.
+    ///
+    /// The C API of LLVM 19 provides no accessors for components of locations.
+    /// So a location is printed and its printed form is parsed on every call,
+    /// and child locations and fused metadata are rebuilt from the text.
+    ///
+    /// Opaque locations are decomposed as their fallback locations because
+    /// they are printed so. It returns `None` for:
+    ///
+    /// - locations of kinds defined outside the builtin dialect,
+    /// - file locations and name locations with names not in UTF-8, and
+    /// - fused locations with metadata that fails to be parsed back.
+    pub fn kind(&self) -> Option<LocationKind<'c>> {
+        let context = unsafe { self.context().to_ref() };
+
+        Some(match OwnedLocation::parse(&self.to_string())? {
+            OwnedLocation::Unknown => LocationKind::Unknown,
+            OwnedLocation::FileLineColumn {
+                filename,
+                line,
+                column,
+            } => LocationKind::FileLineColumn {
+                filename,
+                line,
+                column,
+            },
+            OwnedLocation::Name { name, child } => LocationKind::Name {
+                name,
+                child: child.to_location(context)?,
+            },
+            OwnedLocation::CallSite { callee, caller } => LocationKind::CallSite {
+                callee: callee.to_location(context)?,
+                caller: caller.to_location(context)?,
+            },
+            OwnedLocation::Fused {
+                locations,
+                metadata,
+            } => LocationKind::Fused {
+                locations: locations
+                    .iter()
+                    .map(|location| location.to_location(context))
+                    .collect::<Option<_>>()?,
+                metadata: match metadata {
+                    Some(metadata) => Some(Attribute::parse(context, &metadata)?),
+                    None => None,
+                },
+            },
+            OwnedLocation::Printed(_) => return None,
+        })
+    }
+
     /// Creates a location from a raw object.
     ///
     /// # Safety
@@ -116,6 +215,7 @@ impl Display for Location<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{diagnostic::DiagnosticSeverity, ir::attribute::LocationAttribute};
     use pretty_assertions::{assert_eq, assert_ne};
 
     #[test]
@@ -161,6 +261,231 @@ mod tests {
         Location::new(&Context::new(), "foo", 42, 42).context();
     }
 
+    #[test]
+    fn kind_unknown() {
+        let context = Context::new();
+
+        assert_eq!(
+            Location::unknown(&context).kind(),
+            Some(LocationKind::Unknown)
+        );
+    }
+
+    #[test]
+    fn kind_file_line_column() {
+        let context = Context::new();
+
+        assert_eq!(
+            Location::new(&context, "foo/bar.rs", 42, 7).kind(),
+            Some(LocationKind::FileLineColumn {
+                filename: "foo/bar.rs".into(),
+                line: 42,
+                column: 7
+            })
+        );
+    }
+
+    #[test]
+    fn kind_file_line_column_escaped() {
+        let context = Context::new();
+
+        for filename in ["foo \"bar\"", "foo\\bar", "foo\nbar", "\u{3042}"] {
+            assert_eq!(
+                Location::new(&context, filename, 1, 2).kind(),
+                Some(LocationKind::FileLineColumn {
+                    filename: filename.into(),
+                    line: 1,
+                    column: 2
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn kind_name() {
+        let context = Context::new();
+        let child = Location::new(&context, "foo", 1, 2);
+
+        assert_eq!(
+            Location::name(&context, "bar", child).kind(),
+            Some(LocationKind::Name {
+                name: "bar".into(),
+                child
+            })
+        );
+        assert_eq!(
+            Location::name(&context, "bar", Location::unknown(&context)).kind(),
+            Some(LocationKind::Name {
+                name: "bar".into(),
+                child: Location::unknown(&context)
+            })
+        );
+    }
+
+    #[test]
+    fn kind_call_site() {
+        let context = Context::new();
+        let callee = Location::name(&context, "foo", Location::new(&context, "foo", 1, 2));
+        let caller = Location::call_site(
+            Location::new(&context, "bar", 3, 4),
+            Location::new(&context, "baz", 5, 6),
+        );
+
+        assert_eq!(
+            Location::call_site(callee, caller).kind(),
+            Some(LocationKind::CallSite { callee, caller })
+        );
+    }
+
+    #[test]
+    fn kind_fused() {
+        let context = Context::new();
+        let locations = vec![
+            Location::new(&context, "foo", 1, 2),
+            Location::name(&context, "bar", Location::unknown(&context)),
+        ];
+        let metadata = Attribute::parse(&context, "affine_map<(d0) -> (d0)>").unwrap();
+
+        assert_eq!(
+            Location::fused(&context, &locations, metadata).kind(),
+            Some(LocationKind::Fused {
+                locations: locations.clone(),
+                metadata: Some(metadata)
+            })
+        );
+        assert_eq!(
+            Location::fused(&context, &locations, unsafe { Attribute::null() }).kind(),
+            Some(LocationKind::Fused {
+                locations,
+                metadata: None
+            })
+        );
+    }
+
+    #[test]
+    fn kind_fused_integer_set() {
+        let context = Context::new();
+        let locations = vec![Location::unknown(&context)];
+        let metadata = Attribute::parse(&context, "affine_set<(d0) : (d0 >= 0)>").unwrap();
+
+        assert_eq!(
+            Location::fused(&context, &locations, metadata).kind(),
+            Some(LocationKind::Fused {
+                locations,
+                metadata: Some(metadata)
+            })
+        );
+    }
+
+    #[test]
+    fn kind_none() {
+        let context = Context::new();
+        let location =
+            LocationAttribute::try_from(Attribute::parse(&context, r#"loc("\FF")"#).unwrap())
+                .unwrap()
+                .value();
+
+        assert_eq!(location.kind(), None);
+    }
+
+    #[test]
+    fn kind_fused_with_unparsable_metadata() {
+        let context = Context::new();
+        context.set_allow_unregistered_dialects(true);
+        let metadata = Attribute::parse(&context, "#foo.bar").unwrap();
+        let location = Location::fused(&context, &[Location::unknown(&context)], metadata);
+        context.set_allow_unregistered_dialects(false);
+
+        assert_eq!(location.kind(), None);
+    }
+
+    fn is_caller(location: Option<LocationKind>, line: u32) -> bool {
+        matches!(
+            location,
+            Some(LocationKind::FileLineColumn { filename, line: caller_line, .. })
+                if filename == file!() && caller_line == line as usize
+        )
+    }
+
+    #[test]
+    fn caller() {
+        let context = Context::new();
+
+        assert!(is_caller(Location::caller(&context).kind(), line!()));
+    }
+
+    #[test]
+    fn caller_tracked() {
+        #[track_caller]
+        fn create(context: &Context) -> Location {
+            Location::caller(context)
+        }
+
+        let context = Context::new();
+
+        assert!(is_caller(create(&context).kind(), line!()));
+    }
+
+    #[test]
+    fn with_caller() {
+        let context = Context::new();
+
+        let location = Location::unknown(&context).with_caller();
+
+        assert!(is_caller(location.kind(), line!() - 2));
+
+        let location = Location::new(&context, "foo", 1, 2);
+        let (fused, line) = (location.with_caller(), line!());
+
+        match fused.kind() {
+            Some(LocationKind::Fused {
+                locations,
+                metadata: None,
+            }) => {
+                assert_eq!(locations[0], location);
+                assert!(is_caller(locations[1].kind(), line));
+            }
+            kind => panic!("unexpected location kind: {kind:?}"),
+        }
+    }
+
+    #[test]
+    fn emit_error() {
+        let context = Context::new();
+        let location = Location::new(&context, "foo", 1, 2);
+        let mut diagnostics = vec![];
+
+        context.attach_diagnostic_handler(|diagnostic| {
+            diagnostics.push((
+                diagnostic.to_string(),
+                diagnostic.severity(),
+                diagnostic.location().to_string(),
+            ));
+            true
+        });
+
+        location.emit_error("bar").unwrap();
+
+        assert_eq!(
+            diagnostics,
+            [(
+                "bar".into(),
+                DiagnosticSeverity::Error,
+                "loc(\"foo\":1:2)".into()
+            )]
+        );
+    }
+
+    #[test]
+    fn emit_error_with_nul() {
+        let context = Context::new();
+
+        assert!(matches!(
+            Location::unknown(&context).emit_error("foo\0bar"),
+            Err(Error::Nul(_))
+        ));
+    }
+
     #[test]
     fn equal() {
         let context = Context::new();
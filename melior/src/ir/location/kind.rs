@@ -0,0 +1,39 @@
+use super::Location;
+use crate::ir::Attribute;
+
+/// A kind of a location with its components.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LocationKind<'c> {
+    /// An unknown location.
+    Unknown,
+    /// A file location with line and column numbers.
+    FileLineColumn {
+        /// A filename.
+        filename: String,
+        /// A line number.
+        line: usize,
+        /// A column number.
+        column: usize,
+    },
+    /// A name location.
+    Name {
+        /// A name.
+        name: String,
+        /// A child location.
+        child: Location<'c>,
+    },
+    /// A call site location.
+    CallSite {
+        /// A callee location.
+        callee: Location<'c>,
+        /// A caller location.
+        caller: Location<'c>,
+    },
+    /// A fused location.
+    Fused {
+        /// Fused locations.
+        locations: Vec<Location<'c>>,
+        /// Metadata.
+        metadata: Option<Attribute<'c>>,
+    },
+}
@@ -0,0 +1,337 @@
+use super::{parser::Parser, Location};
+use crate::{
+    context::Context,
+    ir::{attribute::LocationAttribute, Attribute},
+};
+use std::fmt::{self, Display, Formatter};
+
+/// An owned location.
+///
+/// Unlike [`Location`], it does not belong to any context. So it can be kept
+/// in an [`OwnedDiagnostic`](crate::diagnostic::OwnedDiagnostic).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OwnedLocation {
+    /// An unknown location.
+    Unknown,
+    /// A file location with line and column numbers.
+    FileLineColumn {
+        /// A filename.
+        filename: String,
+        /// A line number.
+        line: usize,
+        /// A column number.
+        column: usize,
+    },
+    /// A name location.
+    Name {
+        /// A name.
+        name: String,
+        /// A child location.
+        child: Box<Self>,
+    },
+    /// A call site location.
+    CallSite {
+        /// A callee location.
+        callee: Box<Self>,
+        /// A caller location.
+        caller: Box<Self>,
+    },
+    /// A fused location.
+    Fused {
+        /// Fused locations.
+        locations: Vec<Self>,
+        /// Printed metadata.
+        metadata: Option<String>,
+    },
+    /// A location which cannot be decomposed, in its printed form.
+    Printed(String),
+}
+
+impl OwnedLocation {
+    /// Parses a printed location of the `loc(...)` form.
+    pub fn parse(source: &str) -> Option<Self> {
+        Parser::new(source).parse()
+    }
+
+    /// Converts an owned location into a location in a context.
+    ///
+    /// It returns `None` if metadata of fused locations or printed locations
+    /// cannot be parsed.
+    pub fn to_location<'c>(&self, context: &'c Context) -> Option<Location<'c>> {
+        Some(match self {
+            Self::Unknown => Location::unknown(context),
+            Self::FileLineColumn {
+                filename,
+                line,
+                column,
+            } => Location::new(context, filename, *line, *column),
+            Self::Name { name, child } => {
+                Location::name(context, name, child.to_location(context)?)
+            }
+            Self::CallSite { callee, caller } => {
+                Location::call_site(callee.to_location(context)?, caller.to_location(context)?)
+            }
+            Self::Fused {
+                locations,
+                metadata,
+            } => Location::fused(
+                context,
+                &locations
+                    .iter()
+                    .map(|location| location.to_location(context))
+                    .collect::<Option<Vec<_>>>()?,
+                match metadata {
+                    Some(metadata) => Attribute::parse(context, metadata)?,
+                    None => unsafe { Attribute::null() },
+                },
+            ),
+            Self::Printed(location) => {
+                LocationAttribute::try_from(Attribute::parse(context, location)?)
+                    .ok()?
+                    .value()
+            }
+        })
+    }
+
+    fn write_inner(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Unknown => write!(formatter, "unknown"),
+            Self::FileLineColumn {
+                filename,
+                line,
+                column,
+            } => {
+                write_string(formatter, filename)?;
+                write!(formatter, ":{line}:{column}")
+            }
+            Self::Name { name, child } => {
+                write_string(formatter, name)?;
+
+                if **child != Self::Unknown {
+                    write!(formatter, "(")?;
+                    child.write_inner(formatter)?;
+                    write!(formatter, ")")?;
+                }
+
+                Ok(())
+            }
+            Self::CallSite { callee, caller } => {
+                write!(formatter, "callsite(")?;
+                callee.write_inner(formatter)?;
+                write!(formatter, " at ")?;
+                caller.write_inner(formatter)?;
+                write!(formatter, ")")
+            }
+            Self::Fused {
+                locations,
+                metadata,
+            } => {
+                write!(formatter, "fused")?;
+
+                if let Some(metadata) = metadata {
+                    write!(formatter, "<{metadata}>")?;
+                }
+
+                write!(formatter, "[")?;
+
+                for (index, location) in locations.iter().enumerate() {
+                    if index > 0 {
+                        write!(formatter, ", ")?;
+                    }
+
+                    location.write_inner(formatter)?;
+                }
+
+                write!(formatter, "]")
+            }
+            Self::Printed(location) => write!(formatter, "{location}"),
+        }
+    }
+}
+
+impl Display for OwnedLocation {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        if let Self::Printed(location) = self {
+            return write!(formatter, "{location}");
+        }
+
+        write!(formatter, "loc(")?;
+        self.write_inner(formatter)?;
+        write!(formatter, ")")
+    }
+}
+
+impl From<Location<'_>> for OwnedLocation {
+    fn from(location: Location) -> Self {
+        let string = location.to_string();
+
+        Self::parse(&string).unwrap_or(Self::Printed(string))
+    }
+}
+
+// Writes a string literal escaped in the same way as MLIR.
+fn write_string(formatter: &mut Formatter, string: &str) -> fmt::Result {
+    write!(formatter, "\"")?;
+
+    for &byte in string.as_bytes() {
+        match byte {
+            b'\\' => write!(formatter, "\\\\")?,
+            b'"' => write!(formatter, "\\22")?,
+            b' '..=b'~' => write!(formatter, "{}", byte as char)?,
+            _ => write!(formatter, "\\{byte:02X}")?,
+        }
+    }
+
+    write!(formatter, "\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_unknown() {
+        assert_eq!(
+            OwnedLocation::parse("loc(unknown)"),
+            Some(OwnedLocation::Unknown)
+        );
+    }
+
+    #[test]
+    fn parse_call_site() {
+        assert_eq!(
+            OwnedLocation::parse(r#"loc(callsite("foo"("bar":1:2) at "baz":3:4))"#),
+            Some(OwnedLocation::CallSite {
+                callee: OwnedLocation::Name {
+                    name: "foo".into(),
+                    child: OwnedLocation::FileLineColumn {
+                        filename: "bar".into(),
+                        line: 1,
+                        column: 2
+                    }
+                    .into()
+                }
+                .into(),
+                caller: OwnedLocation::FileLineColumn {
+                    filename: "baz".into(),
+                    line: 3,
+                    column: 4
+                }
+                .into()
+            })
+        );
+    }
+
+    #[test]
+    fn parse_fused() {
+        assert_eq!(
+            OwnedLocation::parse(r#"loc(fused<{foo = "a>b"}>["bar":1:2, unknown])"#),
+            Some(OwnedLocation::Fused {
+                locations: vec![
+                    OwnedLocation::FileLineColumn {
+                        filename: "bar".into(),
+                        line: 1,
+                        column: 2
+                    },
+                    OwnedLocation::Unknown
+                ],
+                metadata: Some(r#"{foo = "a>b"}"#.into())
+            })
+        );
+    }
+
+    #[test]
+    fn parse_fused_integer_set() {
+        assert_eq!(
+            OwnedLocation::parse(
+                "loc(fused<affine_set<(d0)[s0] : (d0 - s0 >= 0, -d0 + 42 >= 0)>>[unknown])"
+            ),
+            Some(OwnedLocation::Fused {
+                locations: vec![OwnedLocation::Unknown],
+                metadata: Some("affine_set<(d0)[s0] : (d0 - s0 >= 0, -d0 + 42 >= 0)>".into())
+            })
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for source in [
+            "",
+            "unknown",
+            "loc(unknown",
+            "loc(unknown))",
+            r#"loc("foo":1)"#,
+            "loc(fused<(]>[unknown])",
+            "loc(fused<{>[unknown])",
+        ] {
+            assert_eq!(OwnedLocation::parse(source), None);
+        }
+    }
+
+    #[test]
+    fn display() {
+        let context = Context::new();
+
+        for location in [
+            Location::unknown(&context),
+            Location::new(&context, "foo \"bar\"\\\n\u{3042}", 1, 2),
+            Location::name(&context, "foo", Location::unknown(&context)),
+            Location::call_site(
+                Location::name(&context, "foo", Location::new(&context, "bar", 1, 2)),
+                Location::fused(
+                    &context,
+                    &[
+                        Location::new(&context, "baz", 3, 4),
+                        Location::unknown(&context),
+                    ],
+                    Attribute::parse(&context, "affine_map<(d0) -> (d0)>").unwrap(),
+                ),
+            ),
+            Location::fused(&context, &[], unsafe { Attribute::null() }),
+        ] {
+            assert_eq!(
+                OwnedLocation::from(location).to_string(),
+                location.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn from_location_printed() {
+        let context = Context::new();
+        let location =
+            LocationAttribute::try_from(Attribute::parse(&context, r#"loc("\FF")"#).unwrap())
+                .unwrap()
+                .value();
+
+        assert_eq!(
+            OwnedLocation::from(location),
+            OwnedLocation::Printed(r#"loc("\FF")"#.into())
+        );
+        assert_eq!(
+            OwnedLocation::from(location).to_location(&context),
+            Some(location)
+        );
+    }
+
+    #[test]
+    fn to_location() {
+        let context = Context::new();
+        let location = Location::call_site(
+            Location::name(&context, "foo", Location::new(&context, "bar", 1, 2)),
+            Location::fused(
+                &context,
+                &[Location::new(&context, "baz", 3, 4)],
+                Attribute::parse(&context, "42").unwrap(),
+            ),
+        );
+
+        assert_eq!(
+            OwnedLocation::parse(&location.to_string())
+                .unwrap()
+                .to_location(&context),
+            Some(location)
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use super::OwnedLocation;
+
+/// A parser of printed locations.
+///
+/// The C API does not expose components of locations. So we decompose their
+/// printed forms instead.
+pub struct Parser<'a> {
+    source: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    pub const fn new(source: &'a str) -> Self {
+        Self { source }
+    }
+
+    /// Parses a top-level location of the `loc(...)` form.
+    pub fn parse(mut self) -> Option<OwnedLocation> {
+        self.expect("loc(")?;
+        let location = self.location()?;
+        self.expect(")")?;
+
+        self.source.is_empty().then_some(location)
+    }
+
+    fn location(&mut self) -> Option<OwnedLocation> {
+        Some(if self.consume("unknown") {
+            OwnedLocation::Unknown
+        } else if self.consume("callsite(") {
+            let callee = self.location()?;
+            self.expect(" at ")?;
+            let caller = self.location()?;
+            self.expect(")")?;
+
+            OwnedLocation::CallSite {
+                callee: callee.into(),
+                caller: caller.into(),
+            }
+        } else if self.consume("fused") {
+            let metadata = if self.consume("<") {
+                Some(self.metadata()?)
+            } else {
+                None
+            };
+            let mut locations = vec![];
+
+            self.expect("[")?;
+
+            if !self.consume("]") {
+                loop {
+                    locations.push(self.location()?);
+
+                    if self.consume("]") {
+                        break;
+                    }
+
+                    self.expect(", ")?;
+                }
+            }
+
+            OwnedLocation::Fused {
+                locations,
+                metadata,
+            }
+        } else {
+            let string = self.string()?;
+
+            if self.consume(":") {
+                let line = self.integer()?;
+                self.expect(":")?;
+                let column = self.integer()?;
+
+                OwnedLocation::FileLineColumn {
+                    filename: string,
+                    line,
+                    column,
+                }
+            } else if self.consume("(") {
+                let child = self.location()?;
+                self.expect(")")?;
+
+                OwnedLocation::Name {
+                    name: string,
+                    child: child.into(),
+                }
+            } else {
+                OwnedLocation::Name {
+                    name: string,
+                    child: OwnedLocation::Unknown.into(),
+                }
+            }
+        })
+    }
+
+    // Parses printed metadata until a closing angle bracket.
+    //
+    // Angle brackets inside other brackets are comparison operators of integer
+    // sets rather than delimiters.
+    fn metadata(&mut self) -> Option<String> {
+        let mut closers = vec![];
+        let mut characters = self.source.char_indices().peekable();
+
+        while let Some((index, character)) = characters.next() {
+            match character {
+                '"' => {
+                    while let Some((_, character)) = characters.next() {
+                        match character {
+                            '\\' => {
+                                characters.next();
+                            }
+                            '"' => break,
+                            _ => {}
+                        }
+                    }
+                }
+                // Skip arrows in affine maps and function types.
+                '-' if characters.peek().map(|&(_, character)| character) == Some('>') => {
+                    characters.next();
+                }
+                '<' => closers.push('>'),
+                '(' => closers.push(')'),
+                '[' => closers.push(']'),
+                '{' => closers.push('}'),
+                '>' if closers.is_empty() => {
+                    let metadata = self.source[..index].to_owned();
+                    self.source = &self.source[index + 1..];
+
+                    return Some(metadata);
+                }
+                '>' if closers.last() != Some(&'>') => {}
+                ')' | ']' | '}' | '>' => {
+                    if closers.pop() != Some(character) {
+                        return None;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    // Parses an escaped string literal.
+    fn string(&mut self) -> Option<String> {
+        self.expect("\"")?;
+
+        let mut bytes = vec![];
+        let mut characters = self.source.char_indices();
+
+        loop {
+            match characters.next()? {
+                (index, '"') => {
+                    self.source = &self.source[index + 1..];
+
+                    return String::from_utf8(bytes).ok();
+                }
+                (_, '\\') => match characters.next()?.1 {
+                    'n' => bytes.push(b'\n'),
+                    't' => bytes.push(b'\t'),
+                    character @ ('"' | '\\') => bytes.push(character as u8),
+                    character => {
+                        let high = character.to_digit(16)?;
+                        let low = characters.next()?.1.to_digit(16)?;
+
+                        bytes.push((high * 16 + low) as u8);
+                    }
+                },
+                (_, character) => {
+                    bytes.extend_from_slice(character.encode_utf8(&mut [0; 4]).as_bytes())
+                }
+            }
+        }
+    }
+
+    fn integer(&mut self) -> Option<usize> {
+        let length = self
+            .source
+            .find(|character: char| !character.is_ascii_digit())
+            .unwrap_or(self.source.len());
+        let integer = self.source[..length].parse().ok()?;
+        self.source = &self.source[length..];
+
+        Some(integer)
+    }
+
+    fn consume(&mut self, token: &str) -> bool {
+        if let Some(source) = self.source.strip_prefix(token) {
+            self.source = source;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Option<()> {
+        self.consume(token).then_some(())
+    }
+}
@@ -1,13 +1,25 @@
-use super::{operation::OperationRefMut, BlockRef, Location, Operation, OperationRef};
+mod owned;
+
+pub use self::owned::OwnedModule;
+use super::{
+    block::BlockLike, operation::OperationRefMut, BlockRef, Location, Operation, OperationRef,
+};
 use crate::{
     context::{Context, ContextRef},
     string_ref::StringRef,
+    Error,
 };
 use mlir_sys::{
-    mlirModuleCreateEmpty, mlirModuleCreateParse, mlirModuleDestroy, mlirModuleFromOperation,
-    mlirModuleGetBody, mlirModuleGetContext, mlirModuleGetOperation, MlirModule,
+    mlirBlockGetFirstOperation, mlirModuleCreateEmpty, mlirModuleCreateParse, mlirModuleDestroy,
+    mlirModuleFromOperation, mlirModuleGetBody, mlirModuleGetContext, mlirModuleGetOperation,
+    mlirOperationCreateParse, mlirOperationGetNextInBlock, MlirModule, MlirStringRef,
+};
+use std::{
+    ffi::{CStr, CString},
+    fs::read_to_string,
+    marker::PhantomData,
+    path::Path,
 };
-use std::{ffi::CString, marker::PhantomData};
 
 /// A module.
 #[derive(Debug)]
@@ -26,10 +38,105 @@ impl<'c> Module<'c> {
     pub fn parse(context: &Context, source: &str) -> Option<Self> {
         // TODO Use a string not null-terminated.
         // Somehow, we still need a null-terminated string for a source.
-        let source = CString::new(source).unwrap();
-        let source = StringRef::from_c_str(&source);
+        Self::parse_c_str(context, &CString::new(source).unwrap())
+    }
+
+    /// Parses a module and returns diagnostics on failure.
+    ///
+    /// It returns an error if a source contains a nul byte.
+    pub fn parse_with_diagnostics(context: &Context, source: &str) -> Result<Self, Error> {
+        let source = CString::new(source)?;
+        let capture = context.capture_diagnostics();
+
+        Self::parse_c_str(context, &source).ok_or_else(|| Error::Parse {
+            r#type: "module",
+            diagnostics: capture.finish(),
+        })
+    }
+
+    fn parse_c_str(context: &Context, source: &CStr) -> Option<Self> {
+        unsafe {
+            Self::from_option_raw(mlirModuleCreateParse(
+                context.to_raw(),
+                StringRef::from_c_str(source).to_raw(),
+            ))
+        }
+    }
+
+    /// Parses a module in a file.
+    ///
+    /// Unlike [`Module::parse`], locations of operations refer to the file. If
+    /// the top-level operation in the file is not a module, it is wrapped in a
+    /// new module located at it.
+    ///
+    /// The C API parses a source with its name only into a single operation.
+    /// So it returns an error if the file contains multiple top-level
+    /// operations in an implicit module, or if it contains a nul byte.
+    pub fn parse_file(context: &'c Context, path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let source = read_to_string(path).map_err(|error| Error::ReadFile {
+            path: path.display().to_string(),
+            message: error.to_string(),
+        })?;
+        let source = CString::new(source)?;
+        let capture = context.capture_diagnostics();
+
+        let Some(operation) = (unsafe {
+            Operation::from_option_raw(mlirOperationCreateParse(
+                context.to_raw(),
+                StringRef::from_c_str(&source).to_raw(),
+                StringRef::new(&path.to_string_lossy()).to_raw(),
+            ))
+        }) else {
+            let diagnostics = capture.take();
 
-        unsafe { Self::from_option_raw(mlirModuleCreateParse(context.to_raw(), source.to_raw())) }
+            // Check if the source is valid but has no single top-level operation.
+            return Err(match Self::parse_c_str(context, &source) {
+                Some(module) => Error::TopLevelOperationCount {
+                    path: path.display().to_string(),
+                    count: operation_count(module.body()),
+                },
+                None => Error::Parse {
+                    r#type: "module",
+                    diagnostics,
+                },
+            });
+        };
+
+        Ok(
+            if operation.name().as_string_ref().as_str() == Ok("builtin.module") {
+                Self::from_operation(operation).expect("module operation")
+            } else {
+                let module = Self::new(operation.location());
+                module.body().append_operation(operation);
+                module
+            },
+        )
+    }
+
+    /// Parses a module in the bytecode format.
+    pub fn parse_bytecode(context: &'c Context, bytecode: &[u8]) -> Result<Self, Error> {
+        let buffer = BytecodeBuffer::new(bytecode);
+        let capture = context.capture_diagnostics();
+
+        unsafe { Self::from_option_raw(mlirModuleCreateParse(context.to_raw(), buffer.to_raw())) }
+            .ok_or_else(|| Error::Parse {
+                r#type: "bytecode",
+                diagnostics: capture.finish(),
+            })
+    }
+
+    /// Moves a module into another context.
+    ///
+    /// The module is serialized in the bytecode format and parsed in the
+    /// context. So dialects used in the module need to be available in the
+    /// context.
+    pub fn move_to_context<'d>(self, context: &'d Context) -> Result<Module<'d>, Error> {
+        let mut bytecode = vec![];
+
+        self.as_operation().write_bytecode(&mut bytecode)?;
+
+        Module::parse_bytecode(context, &bytecode)
     }
 
     /// Converts a module into an operation.
@@ -88,19 +195,79 @@ impl<'c> Module<'c> {
     }
 }
 
+fn operation_count(block: BlockRef) -> usize {
+    let mut count = 0;
+    let mut operation = unsafe { mlirBlockGetFirstOperation(block.to_raw()) };
+
+    while !operation.ptr.is_null() {
+        count += 1;
+        operation = unsafe { mlirOperationGetNextInBlock(operation) };
+    }
+
+    count
+}
+
 impl Drop for Module<'_> {
     fn drop(&mut self) {
         unsafe { mlirModuleDestroy(self.raw) };
     }
 }
 
+// The bytecode reader requires a buffer to be aligned as much as sections in
+// it. So we copy bytecode into a buffer of aligned chunks.
+#[derive(Clone, Copy)]
+#[repr(align(64))]
+struct BytecodeChunk([u8; BYTECODE_CHUNK_SIZE]);
+
+const BYTECODE_CHUNK_SIZE: usize = 64;
+
+struct BytecodeBuffer {
+    chunks: Vec<BytecodeChunk>,
+    length: usize,
+}
+
+impl BytecodeBuffer {
+    fn new(bytecode: &[u8]) -> Self {
+        // Keep at least one null byte at the end for the textual format.
+        let mut chunks =
+            vec![BytecodeChunk([0; BYTECODE_CHUNK_SIZE]); bytecode.len() / BYTECODE_CHUNK_SIZE + 1];
+
+        for (chunk, bytes) in chunks.iter_mut().zip(bytecode.chunks(BYTECODE_CHUNK_SIZE)) {
+            chunk.0[..bytes.len()].copy_from_slice(bytes);
+        }
+
+        Self {
+            chunks,
+            length: bytecode.len(),
+        }
+    }
+
+    fn to_raw(&self) -> MlirStringRef {
+        MlirStringRef {
+            data: self.chunks.as_ptr() as *const _,
+            length: self.length,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        ir::{attribute::StringAttribute, operation::OperationBuilder, Block, Region, RegionLike},
+        diagnostic::DiagnosticSeverity,
+        ir::{
+            attribute::StringAttribute, operation::OperationBuilder, Block, LocationKind,
+            OwnedLocation, Region, RegionLike,
+        },
         test::create_test_context,
     };
+    use indoc::indoc;
+    use std::{
+        env::temp_dir,
+        fs::{remove_file, write},
+        path::PathBuf,
+        process,
+    };
 
     #[test]
     fn new() {
@@ -122,6 +289,220 @@ mod tests {
         assert!(Module::parse(&Context::new(), "module{").is_none());
     }
 
+    #[test]
+    fn parse_with_diagnostics() {
+        let context = create_test_context();
+
+        assert!(Module::parse_with_diagnostics(&context, "module{}").is_ok());
+    }
+
+    #[test]
+    fn parse_with_diagnostics_error() {
+        let context = create_test_context();
+
+        let Err(Error::Parse {
+            r#type: "module",
+            diagnostics,
+        }) = Module::parse_with_diagnostics(&context, "module{")
+        else {
+            panic!("module parse expected to fail");
+        };
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity(), DiagnosticSeverity::Error);
+    }
+
+    #[test]
+    fn parse_with_diagnostics_nul() {
+        assert!(matches!(
+            Module::parse_with_diagnostics(&create_test_context(), "module {}\0"),
+            Err(Error::Nul(_))
+        ));
+    }
+
+    fn parse_temporary_file<'c>(
+        context: &'c Context,
+        name: &str,
+        source: &str,
+    ) -> (PathBuf, Result<Module<'c>, Error>) {
+        let path = temp_dir().join(format!("melior-{}-{name}.mlir", process::id()));
+        write(&path, source).unwrap();
+        let module = Module::parse_file(context, &path);
+        remove_file(&path).unwrap();
+
+        (path, module)
+    }
+
+    #[test]
+    fn parse_file() {
+        let context = create_test_context();
+        let (path, module) = parse_temporary_file(
+            &context,
+            "module",
+            indoc!(
+                "
+                module {
+                  func.func @foo() {
+                    return
+                  }
+                }
+                "
+            ),
+        );
+        let module = module.unwrap();
+
+        assert_eq!(
+            module.body().first_operation().unwrap().location().kind(),
+            Some(LocationKind::FileLineColumn {
+                filename: path.display().to_string(),
+                line: 2,
+                column: 3
+            })
+        );
+    }
+
+    #[test]
+    fn parse_file_without_module() {
+        let context = create_test_context();
+        let (path, module) =
+            parse_temporary_file(&context, "function", "func.func @foo() { return }");
+        let module = module.unwrap();
+
+        assert!(module.as_operation().verify());
+        assert_eq!(
+            module.as_operation().location().kind(),
+            file_line_column(&path, 1, 1)
+        );
+        assert_eq!(
+            module
+                .body()
+                .first_operation()
+                .unwrap()
+                .name()
+                .as_string_ref()
+                .as_str(),
+            Ok("func.func")
+        );
+    }
+
+    fn file_line_column(path: &Path, line: usize, column: usize) -> Option<LocationKind<'static>> {
+        Some(LocationKind::FileLineColumn {
+            filename: path.display().to_string(),
+            line,
+            column,
+        })
+    }
+
+    #[test]
+    fn parse_file_multiple_operations() {
+        let context = create_test_context();
+        let (path, module) = parse_temporary_file(
+            &context,
+            "multiple",
+            indoc!(
+                "
+                func.func private @foo()
+                #foo = affine_map<(d0) -> (d0)>
+                func.func private @bar()
+                "
+            ),
+        );
+
+        assert_eq!(
+            module.err(),
+            Some(Error::TopLevelOperationCount {
+                path: path.display().to_string(),
+                count: 2
+            })
+        );
+    }
+
+    #[test]
+    fn parse_file_error() {
+        let context = create_test_context();
+        let (path, module) = parse_temporary_file(&context, "error", "module {\n  foo\n}");
+
+        let Err(Error::Parse {
+            r#type: "module",
+            diagnostics,
+        }) = module
+        else {
+            panic!("module parse expected to fail");
+        };
+
+        assert!(matches!(
+            diagnostics[0].location(),
+            OwnedLocation::FileLineColumn { filename, line: 2, .. }
+                if *filename == path.display().to_string()
+        ));
+    }
+
+    #[test]
+    fn parse_file_with_nul() {
+        let context = create_test_context();
+        let (_, module) = parse_temporary_file(&context, "nul", "module {}\0");
+
+        assert!(matches!(module, Err(Error::Nul(_))));
+    }
+
+    #[test]
+    fn parse_file_not_found() {
+        assert!(matches!(
+            Module::parse_file(&create_test_context(), "melior-no-such-file.mlir"),
+            Err(Error::ReadFile { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_bytecode() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            "func.func @foo(%arg0 : i32) -> i32 { return %arg0 : i32 }",
+        )
+        .unwrap();
+        let mut bytecode = vec![];
+
+        module.as_operation().write_bytecode(&mut bytecode).unwrap();
+
+        assert_eq!(
+            Module::parse_bytecode(&context, &bytecode)
+                .unwrap()
+                .as_operation()
+                .to_string(),
+            module.as_operation().to_string()
+        );
+    }
+
+    #[test]
+    fn parse_bytecode_error() {
+        assert!(matches!(
+            Module::parse_bytecode(&create_test_context(), b"ML\xefR\x00foo"),
+            Err(Error::Parse {
+                r#type: "bytecode",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn move_to_context() {
+        let context = create_test_context();
+        let other_context = create_test_context();
+        let module = Module::parse(
+            &context,
+            "func.func @foo(%arg0 : i32) -> i32 { return %arg0 : i32 }",
+        )
+        .unwrap();
+        let string = module.as_operation().to_string();
+
+        let module = module.move_to_context(&other_context).unwrap();
+        drop(context);
+
+        assert!(module.as_operation().verify());
+        assert_eq!(module.as_operation().to_string(), string);
+    }
+
     #[test]
     fn from_operation() {
         let context = create_test_context();
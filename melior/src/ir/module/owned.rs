@@ -0,0 +1,256 @@
+use super::Module;
+use crate::{diagnostic::DiagnosticHandlerId, ir::Location, Context};
+use mlir_sys::MlirDiagnosticHandlerID;
+use std::{convert::Infallible, mem::ManuallyDrop, thread};
+
+/// A module owning its context.
+///
+/// Neither [`Context`] nor [`Module`] can be sent to another thread because
+/// objects in a context refer to each other freely. An owned module bundles a
+/// module with a context where nothing else lives. So it can be built on one
+/// thread and handed off to another, e.g. to compile many modules in parallel.
+///
+/// A context and a module are accessible only in closures generic over their
+/// lifetime so that no other object in the context escapes from them.
+/// Diagnostic handlers attached to a context are detached whenever the
+/// closures return because they might not be `Send` or might borrow data on
+/// the current thread.
+///
+/// # Examples
+///
+/// ```
+/// use melior::{
+///     ir::{Location, Module, OwnedModule},
+///     ContextOptions, ThreadPool,
+/// };
+/// use std::thread;
+///
+/// let handles = (0..4)
+///     .map(|index| {
+///         thread::spawn(move || {
+///             let context = ContextOptions::new()
+///                 .thread_pool(ThreadPool::global())
+///                 .build();
+///
+///             OwnedModule::new(context, |context| {
+///                 Module::new(Location::new(context, "foo.mlir", index, 0))
+///             })
+///         })
+///     })
+///     .collect::<Vec<_>>();
+///
+/// for handle in handles {
+///     let mut module = handle.join().unwrap();
+///
+///     assert!(module.with(|_, module| module.as_operation().verify()));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct OwnedModule {
+    // A module needs to be dropped before its context. Its lifetime is never
+    // exposed as `'static`.
+    module: Module<'static>,
+    context: Context,
+    next_handler_id: MlirDiagnosticHandlerID,
+}
+
+impl OwnedModule {
+    /// Creates an owned module built in a context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a built module does not belong to the context.
+    pub fn new(context: Context, build: impl for<'c> FnOnce(&'c Context) -> Module<'c>) -> Self {
+        match Self::try_new(context, |context| Ok::<_, Infallible>(build(context))) {
+            Ok(module) => module,
+            Err(error) => match error {},
+        }
+    }
+
+    /// Creates an owned module built in a context, or returns an error of the
+    /// build.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a built module does not belong to the context.
+    pub fn try_new<E>(
+        context: Context,
+        build: impl for<'c> FnOnce(&'c Context) -> Result<Module<'c>, E>,
+    ) -> Result<Self, E> {
+        let module = build(&context)?;
+
+        assert!(
+            module.context() == context,
+            "module built in another context"
+        );
+
+        let mut this = Self {
+            module: unsafe { into_static(module) },
+            context,
+            next_handler_id: 0,
+        };
+        this.detach_diagnostic_handlers();
+
+        Ok(this)
+    }
+
+    /// Runs a closure with a context and a module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the module is replaced with one in another context.
+    pub fn with<T>(
+        &mut self,
+        function: impl for<'c> FnOnce(&'c Context, &mut Module<'c>) -> T,
+    ) -> T {
+        let this: *mut Self = self;
+        let _guard = Guard(this);
+
+        unsafe {
+            function(
+                &(*this).context,
+                &mut *(&mut (*this).module as *mut Module<'static>).cast(),
+            )
+        }
+    }
+
+    fn restore(&mut self) {
+        self.detach_diagnostic_handlers();
+
+        if self.module.context() != self.context {
+            self.module = unsafe { into_static(Module::new(Location::unknown(&self.context))) };
+
+            if !thread::panicking() {
+                panic!("module replaced with one in another context");
+            }
+        }
+    }
+
+    // Handler IDs are allocated sequentially in a context. So we detach every
+    // handler attached since the last call.
+    fn detach_diagnostic_handlers(&mut self) {
+        let id = self.context.attach_diagnostic_handler(|_| false).to_raw();
+
+        for id in self.next_handler_id..=id {
+            self.context
+                .detach_diagnostic_handler(unsafe { DiagnosticHandlerId::from_raw(id) });
+        }
+
+        self.next_handler_id = id + 1;
+    }
+}
+
+// Nothing else refers to the context. Diagnostic handlers, which might not be
+// `Send`, are detached every time the context is exposed.
+unsafe impl Send for OwnedModule {}
+
+// Restores invariants of an owned module even on panics.
+struct Guard(*mut OwnedModule);
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        unsafe { (*self.0).restore() }
+    }
+}
+
+unsafe fn into_static(module: Module) -> Module<'static> {
+    Module::from_raw(ManuallyDrop::new(module).to_raw())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test::create_test_context, ContextOptions, ThreadPool};
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
+
+    fn create_module(context: Context) -> OwnedModule {
+        OwnedModule::new(context, |context| Module::new(Location::unknown(context)))
+    }
+
+    #[test]
+    fn new() {
+        let mut module = create_module(Context::new());
+
+        assert!(module.with(|_, module| module.as_operation().verify()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_in_another_context() {
+        let other: &Context = Box::leak(Box::new(Context::new()));
+
+        OwnedModule::new(Context::new(), |_| Module::new(Location::unknown(other)));
+    }
+
+    #[test]
+    fn try_new() {
+        let module = OwnedModule::try_new(create_test_context(), |context| {
+            Module::parse_with_diagnostics(context, "func.func @foo() { return }")
+        });
+
+        assert!(module.is_ok());
+    }
+
+    #[test]
+    fn try_new_error() {
+        let module = OwnedModule::try_new(create_test_context(), |context| {
+            Module::parse_with_diagnostics(context, "foo")
+        });
+
+        assert!(module.is_err());
+    }
+
+    #[test]
+    fn send() {
+        let mut module = thread::spawn(|| {
+            create_module(
+                ContextOptions::new()
+                    .thread_pool(ThreadPool::global())
+                    .build(),
+            )
+        })
+        .join()
+        .unwrap();
+
+        assert!(module.with(|_, module| module.as_operation().verify()));
+    }
+
+    #[test]
+    fn replace_module() {
+        let mut module = create_module(Context::new());
+
+        module.with(|context, module| *module = Module::new(Location::unknown(context)));
+
+        assert!(module.with(|_, module| module.as_operation().verify()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn replace_module_in_another_context() {
+        let other: &Context = Box::leak(Box::new(Context::new()));
+        let mut module = create_module(Context::new());
+
+        module.with(|_, module| *module = Module::new(Location::unknown(other)));
+    }
+
+    #[test]
+    fn detach_diagnostic_handlers() {
+        let handled = Arc::new(AtomicBool::new(false));
+        let mut module = create_module(Context::new());
+
+        module.with(|context, _| {
+            let handled = handled.clone();
+
+            context.attach_diagnostic_handler(move |_| {
+                handled.store(true, Ordering::SeqCst);
+                true
+            });
+        });
+        module.with(|context, _| Location::unknown(context).emit_error("foo").unwrap());
+
+        assert!(!handled.load(Ordering::SeqCst));
+    }
+}
@@ -1,16 +1,26 @@
 //! Operations and operation builders.
 
+mod asm_state;
 mod builder;
+mod operand;
 mod printing_flags;
 mod result;
+mod walk_order;
+mod walk_result;
 
 pub use self::{
-    builder::OperationBuilder, printing_flags::OperationPrintingFlags, result::OperationResult,
+    asm_state::AsmState, builder::OperationBuilder, operand::OperationOperand,
+    printing_flags::OperationPrintingFlags, result::OperationResult, walk_order::WalkOrder,
+    walk_result::WalkResult,
+};
+use super::{
+    Attribute, AttributeLike, BlockLike, BlockRef, Identifier, Location, RegionRef, Value,
+    ValueLike,
 };
-use super::{Attribute, AttributeLike, BlockRef, Identifier, Location, RegionRef, Value};
 use crate::{
     context::{Context, ContextRef},
-    utility::{print_callback, print_string_callback},
+    logical_result::LogicalResult,
+    utility::{print_callback, print_string_callback, print_writer_callback, write_callback},
     Error, StringRef,
 };
 use core::{
@@ -18,19 +28,24 @@ use core::{
     mem::{forget, transmute},
 };
 use mlir_sys::{
-    mlirOperationClone, mlirOperationDestroy, mlirOperationDump, mlirOperationEqual,
-    mlirOperationGetAttribute, mlirOperationGetAttributeByName, mlirOperationGetBlock,
-    mlirOperationGetContext, mlirOperationGetLocation, mlirOperationGetName,
+    mlirBytecodeWriterConfigCreate, mlirBytecodeWriterConfigDesiredEmitVersion,
+    mlirBytecodeWriterConfigDestroy, mlirOperationClone, mlirOperationDestroy, mlirOperationDump,
+    mlirOperationEqual, mlirOperationGetAttribute, mlirOperationGetAttributeByName,
+    mlirOperationGetBlock, mlirOperationGetContext, mlirOperationGetLocation, mlirOperationGetName,
     mlirOperationGetNextInBlock, mlirOperationGetNumAttributes, mlirOperationGetNumOperands,
     mlirOperationGetNumRegions, mlirOperationGetNumResults, mlirOperationGetNumSuccessors,
     mlirOperationGetOperand, mlirOperationGetParentOperation, mlirOperationGetRegion,
-    mlirOperationGetResult, mlirOperationGetSuccessor, mlirOperationPrint,
-    mlirOperationPrintWithFlags, mlirOperationRemoveAttributeByName, mlirOperationRemoveFromParent,
-    mlirOperationSetAttributeByName, mlirOperationVerify, MlirOperation,
+    mlirOperationGetResult, mlirOperationGetSuccessor, mlirOperationMoveAfter,
+    mlirOperationMoveBefore, mlirOperationPrint, mlirOperationPrintWithFlags,
+    mlirOperationPrintWithState, mlirOperationRemoveAttributeByName, mlirOperationRemoveFromParent,
+    mlirOperationSetAttributeByName, mlirOperationSetOperand, mlirOperationSetOperands,
+    mlirOperationSetSuccessor, mlirOperationVerify, mlirOperationWalk,
+    mlirOperationWriteBytecodeWithConfig, MlirOperation, MlirWalkResult,
 };
 use std::{
     ffi::c_void,
     fmt::{Debug, Display, Formatter},
+    io::{self, Write},
     marker::PhantomData,
     ops::{Deref, DerefMut},
 };
@@ -82,6 +97,28 @@ impl<'c> Operation<'c> {
         (0..self.operand_count()).map(|index| self.operand(index).expect("valid operand index"))
     }
 
+    /// Sets an operand at a position.
+    pub fn set_operand(&mut self, index: usize, value: Value<'c, '_>) -> Result<(), Error> {
+        if index < self.operand_count() {
+            unsafe { mlirOperationSetOperand(self.raw, index as isize, value.to_raw()) }
+
+            Ok(())
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "operation operand",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Replaces all operands.
+    pub fn set_operands(&mut self, values: &[Value<'c, '_>]) {
+        unsafe {
+            mlirOperationSetOperands(self.raw, values.len() as isize, values.as_ptr() as *const _)
+        }
+    }
+
     /// Returns the number of results.
     pub fn result_count(&self) -> usize {
         unsafe { mlirOperationGetNumResults(self.raw) as usize }
@@ -135,6 +172,13 @@ impl<'c> Operation<'c> {
         unsafe { Location::from_raw(mlirOperationGetLocation(self.raw)) }
     }
 
+    /// Emits an error diagnostic at the location of the operation.
+    ///
+    /// It returns an error if a message contains a nul byte.
+    pub fn emit_error(&self, message: &str) -> Result<(), Error> {
+        self.location().emit_error(message)
+    }
+
     /// Returns the number of successors.
     pub fn successor_count(&self) -> usize {
         unsafe { mlirOperationGetNumSuccessors(self.raw) as usize }
@@ -159,6 +203,21 @@ impl<'c> Operation<'c> {
             .map(|index| self.successor(index).expect("valid successor index"))
     }
 
+    /// Sets a successor at a position.
+    pub fn set_successor(&mut self, index: usize, block: BlockRef<'c, '_>) -> Result<(), Error> {
+        if index < self.successor_count() {
+            unsafe { mlirOperationSetSuccessor(self.raw, index as isize, block.to_raw()) }
+
+            Ok(())
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "successor",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
     /// Returns the number of attributes.
     pub fn attribute_count(&self) -> usize {
         unsafe { mlirOperationGetNumAttributes(self.raw) as usize }
@@ -200,6 +259,15 @@ impl<'c> Operation<'c> {
         .ok_or_else(|| Error::AttributeNotFound(name.into()))
     }
 
+    /// Returns a attribute with the given name cast into a given attribute
+    /// class.
+    pub fn attribute_as<T: TryFrom<Attribute<'c>>>(&self, name: &str) -> Result<T, Error>
+    where
+        Error: From<T::Error>,
+    {
+        Ok(T::try_from(self.attribute(name)?)?)
+    }
+
     /// Checks if the operation has a attribute with the given name.
     pub fn has_attribute(&self, name: &str) -> bool {
         self.attribute(name).is_ok()
@@ -248,11 +316,61 @@ impl<'c> Operation<'c> {
         unsafe { mlirOperationRemoveFromParent(self.raw) }
     }
 
+    /// Walks an operation and its nested operations recursively in a given
+    /// order.
+    ///
+    /// It returns [`WalkResult::Interrupt`] if a callback interrupts the walk,
+    /// or [`WalkResult::Advance`] otherwise.
+    pub fn walk<F: FnMut(OperationRef<'c, '_>) -> WalkResult>(
+        &self,
+        order: WalkOrder,
+        callback: F,
+    ) -> WalkResult {
+        unsafe extern "C" fn handle<'c, F: FnMut(OperationRef<'c, '_>) -> WalkResult>(
+            operation: MlirOperation,
+            data: *mut c_void,
+        ) -> MlirWalkResult {
+            let (callback, result) = &mut *(data as *mut (F, WalkResult));
+            let current = callback(OperationRef::from_raw(operation));
+
+            if current == WalkResult::Interrupt {
+                *result = current;
+            }
+
+            current.to_raw()
+        }
+
+        let mut data = (callback, WalkResult::Advance);
+
+        unsafe {
+            mlirOperationWalk(
+                self.raw,
+                Some(handle::<F>),
+                &mut data as *mut _ as *mut c_void,
+                order.to_raw(),
+            );
+        }
+
+        data.1
+    }
+
     /// Verifies an operation.
     pub fn verify(&self) -> bool {
         unsafe { mlirOperationVerify(self.raw) }
     }
 
+    /// Verifies an operation and returns diagnostics on failure.
+    pub fn verify_with_diagnostics(&self) -> Result<(), Error> {
+        let context = self.context();
+        let capture = unsafe { context.to_ref() }.capture_diagnostics();
+
+        if self.verify() {
+            Ok(())
+        } else {
+            Err(Error::OperationVerify(capture.finish()))
+        }
+    }
+
     /// Dumps an operation.
     pub fn dump(&self) {
         unsafe { mlirOperationDump(self.raw) }
@@ -276,6 +394,127 @@ impl<'c> Operation<'c> {
         Ok(data.0)
     }
 
+    /// Prints an operation with flags into a writer.
+    pub fn print_with_flags<W: fmt::Write>(
+        &self,
+        writer: &mut W,
+        flags: OperationPrintingFlags,
+    ) -> fmt::Result {
+        let mut data = (writer, Ok(()));
+
+        unsafe {
+            mlirOperationPrintWithFlags(
+                self.raw,
+                flags.to_raw(),
+                Some(print_writer_callback::<W>),
+                &mut data as *mut _ as *mut _,
+            );
+        }
+
+        data.1
+    }
+
+    /// Prints an operation with an assembly state into a writer.
+    pub fn print_with_state<W: fmt::Write>(
+        &self,
+        writer: &mut W,
+        state: &AsmState<'c, '_>,
+    ) -> fmt::Result {
+        let mut data = (writer, Ok(()));
+
+        unsafe {
+            mlirOperationPrintWithState(
+                self.raw,
+                state.to_raw(),
+                Some(print_writer_callback::<W>),
+                &mut data as *mut _ as *mut _,
+            );
+        }
+
+        data.1
+    }
+
+    /// Writes an operation with flags into a byte writer.
+    pub fn write_with_flags<W: Write>(
+        &self,
+        writer: &mut W,
+        flags: OperationPrintingFlags,
+    ) -> io::Result<()> {
+        let mut data = (writer, Ok(()));
+
+        unsafe {
+            mlirOperationPrintWithFlags(
+                self.raw,
+                flags.to_raw(),
+                Some(write_callback::<W>),
+                &mut data as *mut _ as *mut _,
+            );
+        }
+
+        data.1
+    }
+
+    /// Writes an operation in the bytecode format.
+    pub fn write_bytecode(&self, writer: &mut impl Write) -> Result<(), Error> {
+        self.write_bytecode_with_config(writer, None)
+    }
+
+    /// Writes an operation in the bytecode format of a given version.
+    ///
+    /// It fails if the version is not supported or if the operation cannot be
+    /// represented in the version.
+    pub fn write_bytecode_with_version(
+        &self,
+        writer: &mut impl Write,
+        version: i64,
+    ) -> Result<(), Error> {
+        self.write_bytecode_with_config(writer, Some(version))
+    }
+
+    fn write_bytecode_with_config<W: Write>(
+        &self,
+        writer: &mut W,
+        version: Option<i64>,
+    ) -> Result<(), Error> {
+        let capture = unsafe { self.context().to_ref() }.capture_diagnostics();
+        let mut data = (writer, Ok::<_, io::Error>(()));
+
+        let result = LogicalResult::from_raw(unsafe {
+            let config = mlirBytecodeWriterConfigCreate();
+
+            if let Some(version) = version {
+                mlirBytecodeWriterConfigDesiredEmitVersion(config, version);
+            }
+
+            let result = mlirOperationWriteBytecodeWithConfig(
+                self.raw,
+                config,
+                Some(write_callback::<W>),
+                &mut data as *mut _ as *mut _,
+            );
+
+            mlirBytecodeWriterConfigDestroy(config);
+
+            result
+        });
+
+        data.1
+            .map_err(|error| Error::WriteBytecode(error.to_string()))?;
+
+        if result.is_success() {
+            Ok(())
+        } else {
+            Err(Error::WriteBytecode(
+                capture
+                    .finish()
+                    .iter()
+                    .map(|diagnostic| diagnostic.message())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ))
+        }
+    }
+
     /// Creates an operation from a raw object.
     ///
     /// # Safety
@@ -450,7 +689,88 @@ pub struct OperationRefMut<'c, 'a> {
     _reference: PhantomData<&'a Operation<'c>>,
 }
 
-impl OperationRefMut<'_, '_> {
+impl<'c, 'a> OperationRefMut<'c, 'a> {
+    /// Moves an operation after another in the same or a different block.
+    pub fn move_after(self, other: OperationRef<'c, 'a>) {
+        unsafe { mlirOperationMoveAfter(self.raw, other.to_raw()) }
+    }
+
+    /// Moves an operation before another in the same or a different block.
+    pub fn move_before(self, other: OperationRef<'c, 'a>) {
+        unsafe { mlirOperationMoveBefore(self.raw, other.to_raw()) }
+    }
+
+    /// Removes an operation from its parent block and destroys it.
+    ///
+    /// It returns an error if any of its results still has uses.
+    ///
+    /// This function is unsafe rather than consuming the operation reference
+    /// safely because operation references, blocks and values are `Copy` and
+    /// nothing ties their lifetimes to the erasure. So references to the
+    /// operation, operations nested in it and its results can survive it in
+    /// safe code.
+    ///
+    /// # Safety
+    ///
+    /// No other references to the operation, operations nested in it or their
+    /// results must be used after the erasure.
+    pub unsafe fn erase(self) -> Result<(), Error> {
+        if let Some(result) = self.results().find(|result| result.uses().next().is_some()) {
+            return Err(Error::ValueInUse(result.to_string()));
+        }
+
+        mlirOperationDestroy(self.raw);
+
+        Ok(())
+    }
+
+    /// Replaces an operation with another one.
+    ///
+    /// The new operation is inserted at the position of the old one and all
+    /// uses of results of the old operation are redirected to the ones of the
+    /// new operation. Then, the old operation is erased.
+    ///
+    /// It returns an error if the operations have different numbers or types
+    /// of results.
+    ///
+    /// # Safety
+    ///
+    /// No other references to the old operation, operations nested in it or
+    /// their results must be used after the replacement as in
+    /// [`erase`](Self::erase).
+    pub unsafe fn replace_with(
+        self,
+        operation: Operation<'c>,
+    ) -> Result<OperationRef<'c, 'a>, Error> {
+        if operation.result_count() != self.result_count() {
+            return Err(Error::ResultCountMismatch {
+                expected: self.result_count(),
+                actual: operation.result_count(),
+            });
+        }
+
+        for (old, new) in self.results().zip(operation.results()) {
+            if old.r#type() != new.r#type() {
+                return Err(Error::ResultTypeMismatch {
+                    expected: old.r#type().to_string(),
+                    actual: new.r#type().to_string(),
+                });
+            }
+        }
+
+        let block: BlockRef<'c, 'a> = BlockRef::from_option_raw(mlirOperationGetBlock(self.raw))
+            .ok_or_else(|| Error::ParentBlockNotFound(self.to_string()))?;
+        let operation = block.insert_operation_before(OperationRef::from_raw(self.raw), operation);
+
+        for (old, new) in self.results().zip(operation.results()) {
+            old.replace_all_uses_with(new.into());
+        }
+
+        self.erase()?;
+
+        Ok(operation)
+    }
+
     /// Converts an operation reference into a raw object.
     pub const fn to_raw(self) -> MlirOperation {
         self.raw
@@ -521,11 +841,54 @@ mod tests {
     use super::*;
     use crate::{
         context::Context,
-        ir::{attribute::StringAttribute, Block, BlockLike, Location, Region, RegionLike, Type},
+        diagnostic::DiagnosticSeverity,
+        ir::{
+            attribute::{IntegerAttribute, StringAttribute},
+            r#type::IntegerType,
+            Block, BlockLike, Location, Module, OwnedLocation, Region, RegionLike, Type,
+        },
         test::create_test_context,
     };
+    use indoc::indoc;
     use pretty_assertions::assert_eq;
 
+    fn parse_walk_module(context: &Context) -> Module {
+        Module::parse(
+            context,
+            indoc!(
+                "
+                module {
+                    func.func @foo() {
+                        return
+                    }
+                    func.func @bar() {
+                        return
+                    }
+                }
+                "
+            ),
+        )
+        .unwrap()
+    }
+
+    fn walk_names(operation: &Operation, order: WalkOrder, result: WalkResult) -> Vec<String> {
+        let mut names = vec![];
+
+        operation.walk(order, |operation| {
+            let name = operation.name();
+            let name = name.as_string_ref().as_str().unwrap();
+            names.push(name.to_owned());
+
+            if name == "func.func" {
+                result
+            } else {
+                WalkResult::Advance
+            }
+        });
+
+        names
+    }
+
     #[test]
     fn new() {
         let context = create_test_context();
@@ -696,6 +1059,91 @@ mod tests {
         )
     }
 
+    #[test]
+    fn attribute_as() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let operation = OperationBuilder::new("foo", Location::unknown(&context))
+            .add_attributes(&[(
+                Identifier::new(&context, "foo"),
+                StringAttribute::new(&context, "bar").into(),
+            )])
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            operation
+                .attribute_as::<StringAttribute>("foo")
+                .map(|attribute| attribute.to_string()),
+            Ok("\"bar\"".into())
+        );
+        assert_eq!(
+            operation
+                .attribute_as::<IntegerAttribute>("foo")
+                .map(|attribute| attribute.value()),
+            Err(Error::AttributeExpected("integer", "\"bar\"".into()))
+        );
+        assert_eq!(
+            operation
+                .attribute_as::<StringAttribute>("bar")
+                .map(|attribute| attribute.to_string()),
+            Err(Error::AttributeNotFound("bar".into()))
+        );
+    }
+
+    #[test]
+    fn emit_error() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let mut messages = vec![];
+
+        context.attach_diagnostic_handler(|diagnostic| {
+            messages.push((diagnostic.to_string(), diagnostic.location().to_string()));
+            true
+        });
+
+        OperationBuilder::new("foo", Location::new(&context, "bar", 1, 2))
+            .build()
+            .unwrap()
+            .emit_error("baz")
+            .unwrap();
+
+        assert_eq!(messages, [("baz".into(), "loc(\"bar\":1:2)".into())]);
+    }
+
+    #[test]
+    fn verify_with_diagnostics() {
+        let context = create_test_context();
+        let location = Location::new(&context, "foo", 1, 2);
+        let module = Module::new(location);
+
+        assert_eq!(module.as_operation().verify_with_diagnostics(), Ok(()));
+
+        module.body().append_operation(
+            OperationBuilder::new("func.return", location)
+                .build()
+                .unwrap(),
+        );
+
+        let Err(Error::OperationVerify(diagnostics)) =
+            module.as_operation().verify_with_diagnostics()
+        else {
+            panic!("operation verification expected to fail");
+        };
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity(), DiagnosticSeverity::Error);
+        assert_eq!(
+            diagnostics[0].location(),
+            &OwnedLocation::FileLineColumn {
+                filename: "foo".into(),
+                line: 1,
+                column: 2
+            }
+        );
+    }
+
     #[test]
     fn clone() {
         let context = create_test_context();
@@ -757,6 +1205,200 @@ mod tests {
         );
     }
 
+    #[test]
+    fn print_with_flags() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let mut string = String::new();
+
+        OperationBuilder::new("foo", Location::unknown(&context))
+            .build()
+            .unwrap()
+            .print_with_flags(
+                &mut string,
+                OperationPrintingFlags::new().enable_debug_info(true, false),
+            )
+            .unwrap();
+
+        assert_eq!(string, "\"foo\"() : () -> () loc(unknown)");
+    }
+
+    #[test]
+    fn to_string_with_skip_regions() {
+        let context = create_test_context();
+        let module = Module::parse(&context, "func.func @foo() { return }").unwrap();
+
+        assert_eq!(
+            module
+                .as_operation()
+                .to_string_with_flags(OperationPrintingFlags::new().skip_regions()),
+            Ok("module {...}".into())
+        );
+    }
+
+    #[test]
+    fn to_string_with_assume_verified() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let module = Module::new(location);
+
+        module.body().append_operation(
+            OperationBuilder::new("func.return", location)
+                .build()
+                .unwrap(),
+        );
+
+        assert!(module
+            .as_operation()
+            .to_string_with_flags(OperationPrintingFlags::new())
+            .unwrap()
+            .contains("\"func.return\"() : () -> ()"));
+        assert!(!module
+            .as_operation()
+            .to_string_with_flags(OperationPrintingFlags::new().assume_verified())
+            .unwrap()
+            .contains("\"func.return\""));
+    }
+
+    #[test]
+    fn to_string_with_value_users() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo(%arg0 : i32) -> i32 {
+                  %0 = arith.addi %arg0, %arg0 : i32
+                  return %0 : i32
+                }
+                "
+            ),
+        )
+        .unwrap();
+
+        assert!(module
+            .as_operation()
+            .to_string_with_flags(OperationPrintingFlags::new().print_value_users())
+            .unwrap()
+            .contains(" // "));
+    }
+
+    #[test]
+    fn to_string_with_elided_resource_string() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                r#"
+                module attributes {foo = dense_resource<blob> : tensor<2xi32>} {
+                }
+
+                {-#
+                  dialect_resources: {
+                    builtin: {
+                      blob: "0x040000000100000002000000"
+                    }
+                  }
+                #-}
+                "#
+            ),
+        )
+        .unwrap();
+
+        assert!(!module
+            .as_operation()
+            .to_string_with_flags(OperationPrintingFlags::new().elide_large_resource_string(1))
+            .unwrap()
+            .contains("0x040000000100000002000000"));
+    }
+
+    #[test]
+    fn print_with_state() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let operation = OperationBuilder::new("foo", Location::unknown(&context))
+            .build()
+            .unwrap();
+        let state = AsmState::for_operation(&operation, OperationPrintingFlags::new());
+        let mut string = String::new();
+
+        operation.print_with_state(&mut string, &state).unwrap();
+
+        assert_eq!(string, "\"foo\"() : () -> ()");
+    }
+
+    #[test]
+    fn write_with_flags() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let mut bytes = vec![];
+
+        OperationBuilder::new("foo", Location::unknown(&context))
+            .build()
+            .unwrap()
+            .write_with_flags(&mut bytes, OperationPrintingFlags::new())
+            .unwrap();
+
+        assert_eq!(bytes, b"\"foo\"() : () -> ()");
+    }
+
+    #[test]
+    fn write_bytecode() {
+        let context = create_test_context();
+        let mut bytecode = vec![];
+
+        Module::new(Location::unknown(&context))
+            .as_operation()
+            .write_bytecode(&mut bytecode)
+            .unwrap();
+
+        assert!(bytecode.starts_with(b"ML\xefR"));
+    }
+
+    #[test]
+    fn write_bytecode_with_version() {
+        let context = create_test_context();
+        let module = Module::new(Location::unknown(&context));
+        let mut bytecode = vec![];
+
+        module
+            .as_operation()
+            .write_bytecode_with_version(&mut bytecode, 6)
+            .unwrap();
+
+        assert!(bytecode.starts_with(b"ML\xefR"));
+        assert!(matches!(
+            module
+                .as_operation()
+                .write_bytecode_with_version(&mut vec![], 1000),
+            Err(Error::WriteBytecode(_))
+        ));
+    }
+
+    #[test]
+    fn write_bytecode_error() {
+        struct FailingWriter;
+
+        impl Write for FailingWriter {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::ErrorKind::Other.into())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let context = create_test_context();
+
+        assert!(matches!(
+            Module::new(Location::unknown(&context))
+                .as_operation()
+                .write_bytecode(&mut FailingWriter),
+            Err(Error::WriteBytecode(_))
+        ));
+    }
+
     #[test]
     fn remove_from_parent() {
         let context = create_test_context();
@@ -824,4 +1466,339 @@ mod tests {
             &operation
         );
     }
+
+    #[test]
+    fn walk_pre_order() {
+        let context = create_test_context();
+        let module = parse_walk_module(&context);
+
+        assert_eq!(
+            walk_names(
+                &module.as_operation(),
+                WalkOrder::PreOrder,
+                WalkResult::Advance
+            ),
+            [
+                "builtin.module",
+                "func.func",
+                "func.return",
+                "func.func",
+                "func.return"
+            ]
+        );
+    }
+
+    #[test]
+    fn walk_post_order() {
+        let context = create_test_context();
+        let module = parse_walk_module(&context);
+
+        assert_eq!(
+            walk_names(
+                &module.as_operation(),
+                WalkOrder::PostOrder,
+                WalkResult::Advance
+            ),
+            [
+                "func.return",
+                "func.func",
+                "func.return",
+                "func.func",
+                "builtin.module"
+            ]
+        );
+    }
+
+    #[test]
+    fn walk_skip() {
+        let context = create_test_context();
+        let module = parse_walk_module(&context);
+
+        assert_eq!(
+            walk_names(
+                &module.as_operation(),
+                WalkOrder::PreOrder,
+                WalkResult::Skip
+            ),
+            ["builtin.module", "func.func", "func.func"]
+        );
+    }
+
+    #[test]
+    fn walk_interrupt() {
+        let context = create_test_context();
+        let module = parse_walk_module(&context);
+        let mut count = 0;
+
+        assert_eq!(
+            module
+                .as_operation()
+                .walk(WalkOrder::PreOrder, |operation| {
+                    count += 1;
+
+                    if operation.name() == Identifier::new(&context, "func.func") {
+                        WalkResult::Interrupt
+                    } else {
+                        WalkResult::Advance
+                    }
+                }),
+            WalkResult::Interrupt
+        );
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn set_operand() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location), (r#type, location)]);
+        let first_argument: Value = block.argument(0).unwrap().into();
+        let second_argument: Value = block.argument(1).unwrap().into();
+
+        let mut operation = OperationBuilder::new("foo", location)
+            .add_operands(&[first_argument])
+            .build()
+            .unwrap();
+
+        operation.set_operand(0, second_argument).unwrap();
+
+        assert_eq!(operation.operand(0), Ok(second_argument));
+        assert!(matches!(
+            operation.set_operand(1, second_argument),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn set_operands() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location), (r#type, location)]);
+        let first_argument: Value = block.argument(0).unwrap().into();
+        let second_argument: Value = block.argument(1).unwrap().into();
+
+        let mut operation = OperationBuilder::new("foo", location)
+            .add_operands(&[first_argument])
+            .build()
+            .unwrap();
+
+        operation.set_operands(&[second_argument, first_argument]);
+
+        assert_eq!(
+            operation.operands().collect::<Vec<_>>(),
+            [second_argument, first_argument]
+        );
+    }
+
+    #[test]
+    fn set_successor() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let region = Region::new();
+        let first_block = region.append_block(Block::new(&[]));
+        let second_block = region.append_block(Block::new(&[]));
+
+        let mut operation = OperationBuilder::new("foo", Location::unknown(&context))
+            .add_successors(&[&*first_block])
+            .build()
+            .unwrap();
+
+        operation.set_successor(0, second_block).unwrap();
+
+        assert_eq!(operation.successor(0), Ok(second_block));
+        assert!(matches!(
+            operation.set_successor(1, second_block),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn move_after() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let block = Block::new(&[]);
+
+        let first = block.append_operation(OperationBuilder::new("foo", location).build().unwrap());
+        let second =
+            block.append_operation(OperationBuilder::new("bar", location).build().unwrap());
+
+        block.first_operation_mut().unwrap().move_after(second);
+
+        assert_eq!(block.first_operation(), Some(second));
+        assert_eq!(second.next_in_block(), Some(first));
+    }
+
+    #[test]
+    fn move_before() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let block = Block::new(&[]);
+
+        let first = block.append_operation(OperationBuilder::new("foo", location).build().unwrap());
+        let second =
+            block.append_operation(OperationBuilder::new("bar", location).build().unwrap());
+
+        first.next_in_block_mut().unwrap().move_before(first);
+
+        assert_eq!(block.first_operation(), Some(second));
+        assert_eq!(second.next_in_block(), Some(first));
+    }
+
+    #[test]
+    fn erase() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let block = Block::new(&[]);
+
+        block.append_operation(OperationBuilder::new("foo", location).build().unwrap());
+        let second =
+            block.append_operation(OperationBuilder::new("bar", location).build().unwrap());
+
+        unsafe { block.first_operation_mut().unwrap().erase() }.unwrap();
+
+        assert_eq!(block.first_operation(), Some(second));
+        assert_eq!(second.next_in_block(), None);
+    }
+
+    #[test]
+    fn erase_in_use() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let block = Block::new(&[]);
+
+        let first = block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_results(&[Type::index(&context)])
+                .build()
+                .unwrap(),
+        );
+        block.append_operation(
+            OperationBuilder::new("bar", location)
+                .add_operands(&[first.result(0).unwrap().into()])
+                .build()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            unsafe { block.first_operation_mut().unwrap().erase() },
+            Err(Error::ValueInUse(first.result(0).unwrap().to_string()))
+        );
+        assert_eq!(block.first_operation(), Some(first));
+    }
+
+    #[test]
+    fn replace_with() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[]);
+
+        let first = block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_results(&[r#type])
+                .build()
+                .unwrap(),
+        );
+        let user = block.append_operation(
+            OperationBuilder::new("bar", location)
+                .add_operands(&[first.result(0).unwrap().into()])
+                .build()
+                .unwrap(),
+        );
+
+        let replacement = unsafe {
+            block.first_operation_mut().unwrap().replace_with(
+                OperationBuilder::new("baz", location)
+                    .add_results(&[r#type])
+                    .build()
+                    .unwrap(),
+            )
+        }
+        .unwrap();
+
+        assert_eq!(block.first_operation(), Some(replacement));
+        assert_eq!(replacement.next_in_block(), Some(user));
+        assert_eq!(user.operand(0), Ok(replacement.result(0).unwrap().into()));
+    }
+
+    #[test]
+    fn replace_with_result_count_mismatch() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let block = Block::new(&[]);
+
+        block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_results(&[Type::index(&context)])
+                .build()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            unsafe {
+                block
+                    .first_operation_mut()
+                    .unwrap()
+                    .replace_with(OperationBuilder::new("bar", location).build().unwrap())
+            },
+            Err(Error::ResultCountMismatch {
+                expected: 1,
+                actual: 0
+            })
+        );
+    }
+
+    #[test]
+    fn replace_with_result_type_mismatch() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let block = Block::new(&[]);
+
+        block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_results(&[Type::index(&context)])
+                .build()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            unsafe {
+                block.first_operation_mut().unwrap().replace_with(
+                    OperationBuilder::new("bar", location)
+                        .add_results(&[IntegerType::new(&context, 32).into()])
+                        .build()
+                        .unwrap(),
+                )
+            },
+            Err(Error::ResultTypeMismatch {
+                expected: "index".into(),
+                actual: "i32".into(),
+            })
+        );
+        assert_eq!(
+            block.first_operation().unwrap().name(),
+            Identifier::new(&context, "foo")
+        );
+    }
 }
@@ -0,0 +1,112 @@
+use super::{Operation, OperationPrintingFlags};
+use crate::ir::ValueLike;
+use mlir_sys::{
+    mlirAsmStateCreateForOperation, mlirAsmStateCreateForValue, mlirAsmStateDestroy, MlirAsmState,
+};
+use std::marker::PhantomData;
+
+/// An assembly state.
+///
+/// It caches names of values in an operation so that they are printed
+/// consistently and efficiently across multiple printer calls.
+pub struct AsmState<'c, 'a> {
+    raw: MlirAsmState,
+    _operation: PhantomData<&'a Operation<'c>>,
+}
+
+impl<'c, 'a> AsmState<'c, 'a> {
+    /// Creates an assembly state for an operation.
+    pub fn for_operation(operation: &'a Operation<'c>, flags: OperationPrintingFlags) -> Self {
+        unsafe {
+            Self::from_raw(mlirAsmStateCreateForOperation(
+                operation.to_raw(),
+                flags.to_raw(),
+            ))
+        }
+    }
+
+    /// Creates an assembly state for a parent operation of a value.
+    ///
+    /// It returns `None` if a value is a block argument of a block not in any
+    /// operation.
+    pub fn for_value(
+        value: impl ValueLike<'c> + 'a,
+        flags: OperationPrintingFlags,
+    ) -> Option<Self> {
+        unsafe { Self::from_option_raw(mlirAsmStateCreateForValue(value.to_raw(), flags.to_raw())) }
+    }
+
+    /// Creates an assembly state from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirAsmState) -> Self {
+        Self {
+            raw,
+            _operation: Default::default(),
+        }
+    }
+
+    /// Creates an optional assembly state from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_option_raw(raw: MlirAsmState) -> Option<Self> {
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(Self::from_raw(raw))
+        }
+    }
+
+    /// Converts an assembly state into a raw object.
+    pub const fn to_raw(&self) -> MlirAsmState {
+        self.raw
+    }
+}
+
+impl Drop for AsmState<'_, '_> {
+    fn drop(&mut self) {
+        unsafe { mlirAsmStateDestroy(self.raw) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{Block, BlockLike, Location, Module, Type},
+        test::create_test_context,
+    };
+
+    #[test]
+    fn for_operation() {
+        let context = create_test_context();
+        let module = Module::new(Location::unknown(&context));
+
+        AsmState::for_operation(&module.as_operation(), OperationPrintingFlags::new());
+    }
+
+    #[test]
+    fn for_value() {
+        let context = create_test_context();
+        let module = Module::new(Location::unknown(&context));
+        let block = module.body();
+        let argument = block.add_argument(Type::index(&context), Location::unknown(&context));
+
+        assert!(AsmState::for_value(argument, OperationPrintingFlags::new()).is_some());
+    }
+
+    #[test]
+    fn for_value_without_parent() {
+        let context = create_test_context();
+        let block = Block::new(&[(Type::index(&context), Location::unknown(&context))]);
+
+        assert!(
+            AsmState::for_value(block.argument(0).unwrap(), OperationPrintingFlags::new())
+                .is_none()
+        );
+    }
+}
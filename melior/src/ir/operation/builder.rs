@@ -24,7 +24,14 @@ pub struct OperationBuilder<'c> {
 
 impl<'c> OperationBuilder<'c> {
     /// Creates an operation builder.
+    ///
+    /// If the `track-caller` feature is enabled, a location of a caller in Rust
+    /// source code is attached to a given location.
+    #[cfg_attr(feature = "track-caller", track_caller)]
     pub fn new(name: &str, location: Location<'c>) -> Self {
+        #[cfg(feature = "track-caller")]
+        let location = location.with_caller();
+
         Self {
             raw: unsafe { mlirOperationStateGet(StringRef::new(name).to_raw(), location.to_raw()) },
             _context: Default::default(),
@@ -151,6 +158,22 @@ mod tests {
             .unwrap();
     }
 
+    #[cfg(feature = "track-caller")]
+    #[test]
+    fn new_with_caller() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let operation = OperationBuilder::new("foo", Location::unknown(&context))
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            operation.location().kind(),
+            Some(crate::ir::LocationKind::FileLineColumn { filename, .. }) if filename == file!()
+        ));
+    }
+
     #[test]
     fn add_operands() {
         let context = create_test_context();
@@ -0,0 +1,62 @@
+use crate::ir::OperationRef;
+use mlir_sys::{
+    mlirOpOperandGetNextUse, mlirOpOperandGetOperandNumber, mlirOpOperandGetOwner,
+    mlirOpOperandIsNull, MlirOpOperand,
+};
+use std::marker::PhantomData;
+
+/// An operation operand.
+///
+/// It represents a use of a value by an operation.
+#[derive(Clone, Copy, Debug)]
+pub struct OperationOperand<'c, 'a> {
+    raw: MlirOpOperand,
+    _reference: PhantomData<OperationRef<'c, 'a>>,
+}
+
+impl<'c, 'a> OperationOperand<'c, 'a> {
+    /// Returns an owner operation.
+    pub fn owner(&self) -> OperationRef<'c, 'a> {
+        unsafe { OperationRef::from_raw(mlirOpOperandGetOwner(self.raw)) }
+    }
+
+    /// Returns an operand number in an owner operation.
+    pub fn operand_number(&self) -> usize {
+        unsafe { mlirOpOperandGetOperandNumber(self.raw) as usize }
+    }
+
+    /// Returns the next use of the same value.
+    pub fn next_use(&self) -> Option<Self> {
+        unsafe { Self::from_option_raw(mlirOpOperandGetNextUse(self.raw)) }
+    }
+
+    /// Creates an operation operand from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirOpOperand) -> Self {
+        Self {
+            raw,
+            _reference: Default::default(),
+        }
+    }
+
+    /// Creates an optional operation operand from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_option_raw(raw: MlirOpOperand) -> Option<Self> {
+        if mlirOpOperandIsNull(raw) {
+            None
+        } else {
+            Some(Self::from_raw(raw))
+        }
+    }
+
+    /// Converts an operation operand into a raw object.
+    pub const fn to_raw(self) -> MlirOpOperand {
+        self.raw
+    }
+}
@@ -1,7 +1,9 @@
 use mlir_sys::{
-    mlirOpPrintingFlagsCreate, mlirOpPrintingFlagsDestroy,
-    mlirOpPrintingFlagsElideLargeElementsAttrs, mlirOpPrintingFlagsEnableDebugInfo,
-    mlirOpPrintingFlagsPrintGenericOpForm, mlirOpPrintingFlagsUseLocalScope, MlirOpPrintingFlags,
+    mlirOpPrintingFlagsAssumeVerified, mlirOpPrintingFlagsCreate, mlirOpPrintingFlagsDestroy,
+    mlirOpPrintingFlagsElideLargeElementsAttrs, mlirOpPrintingFlagsElideLargeResourceString,
+    mlirOpPrintingFlagsEnableDebugInfo, mlirOpPrintingFlagsPrintGenericOpForm,
+    mlirOpPrintingFlagsPrintValueUsers, mlirOpPrintingFlagsSkipRegions,
+    mlirOpPrintingFlagsUseLocalScope, MlirOpPrintingFlags,
 };
 
 /// Operation printing flags.
@@ -21,6 +23,13 @@ impl OperationPrintingFlags {
         self
     }
 
+    /// Elides large resource strings.
+    pub fn elide_large_resource_string(self, limit: usize) -> Self {
+        unsafe { mlirOpPrintingFlagsElideLargeResourceString(self.0, limit as isize) }
+
+        self
+    }
+
     /// Enables debug info.
     pub fn enable_debug_info(self, enabled: bool, pretty_form: bool) -> Self {
         unsafe { mlirOpPrintingFlagsEnableDebugInfo(self.0, enabled, pretty_form) }
@@ -42,6 +51,29 @@ impl OperationPrintingFlags {
         self
     }
 
+    /// Prints users of values as comments.
+    pub fn print_value_users(self) -> Self {
+        unsafe { mlirOpPrintingFlagsPrintValueUsers(self.0) }
+
+        self
+    }
+
+    /// Assumes operations are verified.
+    ///
+    /// Operations are printed in a custom form even if they are invalid.
+    pub fn assume_verified(self) -> Self {
+        unsafe { mlirOpPrintingFlagsAssumeVerified(self.0) }
+
+        self
+    }
+
+    /// Skips regions.
+    pub fn skip_regions(self) -> Self {
+        unsafe { mlirOpPrintingFlagsSkipRegions(self.0) }
+
+        self
+    }
+
     /// Converts a printing flags into a raw object.
     pub const fn to_raw(&self) -> MlirOpPrintingFlags {
         self.0
@@ -0,0 +1,20 @@
+use mlir_sys::{MlirWalkOrder, MlirWalkOrder_MlirWalkPostOrder, MlirWalkOrder_MlirWalkPreOrder};
+
+/// An order of operation walk.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WalkOrder {
+    /// Visits an operation before its nested operations.
+    PreOrder,
+    /// Visits an operation after its nested operations.
+    PostOrder,
+}
+
+impl WalkOrder {
+    /// Converts a walk order into a raw object.
+    pub const fn to_raw(self) -> MlirWalkOrder {
+        match self {
+            Self::PreOrder => MlirWalkOrder_MlirWalkPreOrder,
+            Self::PostOrder => MlirWalkOrder_MlirWalkPostOrder,
+        }
+    }
+}
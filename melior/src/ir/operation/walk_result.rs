@@ -0,0 +1,28 @@
+use mlir_sys::{
+    MlirWalkResult, MlirWalkResult_MlirWalkResultAdvance, MlirWalkResult_MlirWalkResultInterrupt,
+    MlirWalkResult_MlirWalkResultSkip,
+};
+
+/// A result of a callback of operation walk.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WalkResult {
+    /// Continues the walk.
+    Advance,
+    /// Stops the walk immediately.
+    Interrupt,
+    /// Skips nested operations of the current operation.
+    ///
+    /// This is valid only in a pre-order walk.
+    Skip,
+}
+
+impl WalkResult {
+    /// Converts a walk result into a raw object.
+    pub const fn to_raw(self) -> MlirWalkResult {
+        match self {
+            Self::Advance => MlirWalkResult_MlirWalkResultAdvance,
+            Self::Interrupt => MlirWalkResult_MlirWalkResultInterrupt,
+            Self::Skip => MlirWalkResult_MlirWalkResultSkip,
+        }
+    }
+}
@@ -120,12 +120,64 @@ impl Eq for RegionRef<'_, '_> {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        dialect::cf,
+        ir::{
+            operation::{OperationBuilder, OperationPrintingFlags},
+            r#type::Type,
+            BlockLike, Location, Module,
+        },
+        test::create_test_context,
+        Error,
+    };
+    use indoc::indoc;
 
     #[test]
     fn new() {
         Region::new();
     }
 
+    #[test]
+    fn print_with_flags() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo(%arg0 : i32) -> i32 {
+                  %0 = arith.addi %arg0, %arg0 : i32
+                  return %0 : i32
+                }
+                "
+            ),
+        )
+        .unwrap();
+        let mut string = String::new();
+
+        module
+            .body()
+            .first_operation()
+            .unwrap()
+            .region(0)
+            .unwrap()
+            .print_with_flags(&mut string, OperationPrintingFlags::new())
+            .unwrap();
+
+        assert!(string.starts_with("{\n^bb0(%arg0: i32):\n  %0 = arith.addi"));
+        assert!(string.ends_with("\n}\n"));
+    }
+
+    #[test]
+    fn print_with_flags_empty() {
+        let mut string = String::new();
+
+        Region::new()
+            .print_with_flags(&mut string, OperationPrintingFlags::new())
+            .unwrap();
+
+        assert_eq!(string, "{\n}\n");
+    }
+
     #[test]
     fn first_block() {
         assert!(Region::new().first_block().is_none());
@@ -161,6 +213,173 @@ mod tests {
         assert_eq!(region.first_block(), Some(block));
     }
 
+    #[test]
+    fn detach_block() {
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+
+        let block = unsafe { region.detach_block(block) }.unwrap();
+
+        assert!(region.first_block().is_none());
+        assert_eq!(block.parent_region(), None);
+    }
+
+    #[test]
+    fn detach_block_not_found() {
+        let region = Region::new();
+        let other_region = Region::new();
+        let block = other_region.append_block(Block::new(&[]));
+
+        assert!(matches!(
+            unsafe { region.detach_block(block) },
+            Err(Error::BlockNotFound(_))
+        ));
+        assert_eq!(other_region.first_block(), Some(block));
+    }
+
+    #[test]
+    fn detach_block_with_predecessor() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+        let other_block = region.append_block(Block::new(&[]));
+
+        block.append_operation(cf::br(&other_block, &[], location));
+
+        assert!(matches!(
+            unsafe { region.detach_block(other_block) },
+            Err(Error::BlockInUse(_))
+        ));
+        assert_eq!(block.next_in_region(), Some(other_block));
+    }
+
+    #[test]
+    fn erase_block() {
+        let region = Region::new();
+        let first_block = region.append_block(Block::new(&[]));
+        let second_block = region.append_block(Block::new(&[]));
+
+        unsafe { region.erase_block(first_block) }.unwrap();
+
+        assert_eq!(region.first_block(), Some(second_block));
+        assert_eq!(second_block.next_in_region(), None);
+    }
+
+    #[test]
+    fn erase_block_not_found() {
+        let region = Region::new();
+        let other_region = Region::new();
+        let block = other_region.append_block(Block::new(&[]));
+
+        assert!(matches!(
+            unsafe { region.erase_block(block) },
+            Err(Error::BlockNotFound(_))
+        ));
+        assert_eq!(other_region.first_block(), Some(block));
+    }
+
+    #[test]
+    fn erase_block_with_argument_in_use() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[(Type::index(&context), location)]));
+        let other_block = region.append_block(Block::new(&[]));
+
+        other_block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[block.argument(0).unwrap().into()])
+                .build()
+                .unwrap(),
+        );
+
+        assert!(matches!(
+            unsafe { region.erase_block(block) },
+            Err(Error::ValueInUse(_))
+        ));
+        assert_eq!(region.first_block(), Some(block));
+    }
+
+    #[test]
+    fn erase_block_with_result_in_use() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+        let other_block = region.append_block(Block::new(&[]));
+
+        let operation = block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_results(&[Type::index(&context)])
+                .build()
+                .unwrap(),
+        );
+        other_block.append_operation(
+            OperationBuilder::new("bar", location)
+                .add_operands(&[operation.result(0).unwrap().into()])
+                .build()
+                .unwrap(),
+        );
+
+        assert!(matches!(
+            unsafe { region.erase_block(block) },
+            Err(Error::ValueInUse(_))
+        ));
+    }
+
+    #[test]
+    fn erase_block_with_internal_uses() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[(Type::index(&context), location)]));
+
+        block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[block.argument(0).unwrap().into()])
+                .build()
+                .unwrap(),
+        );
+
+        unsafe { region.erase_block(block) }.unwrap();
+
+        assert!(region.first_block().is_none());
+    }
+
+    #[test]
+    fn erase_block_with_predecessor() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+        let other_block = region.append_block(Block::new(&[]));
+
+        block.append_operation(cf::br(&other_block, &[], location));
+
+        assert!(matches!(
+            unsafe { region.erase_block(other_block) },
+            Err(Error::BlockInUse(_))
+        ));
+        assert_eq!(block.next_in_region(), Some(other_block));
+    }
+
+    #[test]
+    fn move_block() {
+        let region = Region::new();
+        let other_region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+
+        let block = other_region.append_block(unsafe { region.detach_block(block) }.unwrap());
+
+        assert!(region.first_block().is_none());
+        assert_eq!(other_region.first_block(), Some(block));
+        assert_eq!(block.parent_region().as_deref(), Some(&other_region));
+    }
+
     #[test]
     fn equal() {
         let region = Region::new();
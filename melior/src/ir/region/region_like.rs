@@ -1,7 +1,18 @@
-use crate::ir::{Block, BlockRef};
+use crate::{
+    ir::{
+        block::print_region_with_flags, operation::OperationPrintingFlags, Block, BlockLike,
+        BlockRef, OperationRef, RegionRef, ValueLike,
+    },
+    Error,
+};
 use mlir_sys::{
-    mlirRegionAppendOwnedBlock, mlirRegionGetFirstBlock, mlirRegionInsertOwnedBlockAfter,
-    mlirRegionInsertOwnedBlockBefore, MlirRegion,
+    mlirBlockDetach, mlirBlockEqual, mlirBlockGetParentOperation, mlirOperationGetBlock,
+    mlirOperationGetNextInBlock, mlirRegionAppendOwnedBlock, mlirRegionGetFirstBlock,
+    mlirRegionInsertOwnedBlockAfter, mlirRegionInsertOwnedBlockBefore, MlirBlock, MlirRegion,
+};
+use std::{
+    fmt::{self, Display},
+    iter::successors,
 };
 
 /// A region-like trait.
@@ -54,4 +65,114 @@ pub trait RegionLike<'c, 'a>: Copy {
             r#ref
         }
     }
+
+    /// Detaches a block from a region and returns its ownership.
+    ///
+    /// The detached block can be inserted into another region to move it
+    /// between regions.
+    ///
+    /// It returns an error if the block is not in the region, if any of its
+    /// arguments or results of its operations are used outside of it, or if
+    /// any other block branches to it.
+    ///
+    /// # Safety
+    ///
+    /// Block references, operation references and values are `Copy` and can
+    /// survive the detachment. So no references to the block, operations in it
+    /// or their results must be used after the returned block is dropped or
+    /// after its new parent region is dropped.
+    unsafe fn detach_block(self, block: BlockRef<'c, 'a>) -> Result<Block<'c>, Error> {
+        if block.parent_region() != Some(RegionRef::from_raw(self.to_raw())) {
+            return Err(Error::BlockNotFound(block.to_string()));
+        }
+
+        for index in 0..block.argument_count() {
+            check_uses(block.argument(index)?, block.to_raw())?;
+        }
+
+        for operation in operations(block) {
+            for result in operation.results() {
+                check_uses(result, block.to_raw())?;
+            }
+        }
+
+        for other in successors(self.first_block(), |block| block.next_in_region()) {
+            if other != block
+                && operations(other)
+                    .any(|operation| operation.successors().any(|successor| successor == block))
+            {
+                return Err(Error::BlockInUse(block.to_string()));
+            }
+        }
+
+        mlirBlockDetach(block.to_raw());
+
+        Ok(Block::from_raw(block.to_raw()))
+    }
+
+    /// Erases a block in a region.
+    ///
+    /// It returns an error in the same cases as
+    /// [`detach_block`](Self::detach_block).
+    ///
+    /// # Safety
+    ///
+    /// No references to the block, operations in it or their results must be
+    /// used after the erasure.
+    unsafe fn erase_block(self, block: BlockRef<'c, 'a>) -> Result<(), Error> {
+        self.detach_block(block).map(drop)
+    }
+
+    /// Prints a region with flags into a writer.
+    ///
+    /// The C API does not support printing regions with flags. So blocks in
+    /// the region are printed separately with an assembly state of its
+    /// top-level operation.
+    ///
+    /// The flags apply to block headers only partially as described in
+    /// [`BlockLike::print_with_flags`].
+    fn print_with_flags(
+        self,
+        writer: &mut impl fmt::Write,
+        flags: OperationPrintingFlags,
+    ) -> fmt::Result {
+        print_region_with_flags(self.first_block(), writer, flags)
+    }
+}
+
+fn operations<'c, 'a>(block: BlockRef<'c, 'a>) -> impl Iterator<Item = OperationRef<'c, 'a>> {
+    successors(block.first_operation(), |operation| unsafe {
+        OperationRef::from_option_raw(mlirOperationGetNextInBlock(operation.to_raw()))
+    })
+}
+
+fn check_uses<'c>(value: impl ValueLike<'c> + Display, block: MlirBlock) -> Result<(), Error> {
+    if value
+        .uses()
+        .any(|operand| !is_in_block(operand.owner(), block))
+    {
+        Err(Error::ValueInUse(value.to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+fn is_in_block(operation: OperationRef, block: MlirBlock) -> bool {
+    let mut operation = operation.to_raw();
+
+    loop {
+        let parent = unsafe { mlirOperationGetBlock(operation) };
+
+        if parent.ptr.is_null() {
+            return false;
+        } else if unsafe { mlirBlockEqual(parent, block) } {
+            return true;
+        }
+
+        operation = unsafe { mlirBlockGetParentOperation(parent) };
+
+        if operation.ptr.is_null() {
+            return false;
+        }
+    }
 }
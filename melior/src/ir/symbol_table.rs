@@ -0,0 +1,471 @@
+mod symbol_visibility;
+
+pub use self::symbol_visibility::SymbolVisibility;
+use super::{
+    attribute::StringAttribute,
+    operation::{OperationRef, OperationRefMut},
+    Operation,
+};
+use crate::{logical_result::LogicalResult, string_ref::StringRef, Error};
+use mlir_sys::{
+    mlirSymbolTableCreate, mlirSymbolTableDestroy, mlirSymbolTableErase,
+    mlirSymbolTableGetSymbolAttributeName, mlirSymbolTableGetVisibilityAttributeName,
+    mlirSymbolTableInsert, mlirSymbolTableLookup, mlirSymbolTableReplaceAllSymbolUses,
+    mlirSymbolTableWalkSymbolTables, MlirOperation, MlirSymbolTable,
+};
+use std::ffi::c_void;
+
+/// A symbol table.
+///
+/// It caches symbols of a symbol table operation, such as a module, for fast
+/// lookup.
+#[derive(Debug)]
+pub struct SymbolTable<'c, 'a> {
+    raw: MlirSymbolTable,
+    operation: OperationRef<'c, 'a>,
+}
+
+impl<'c, 'a> SymbolTable<'c, 'a> {
+    /// Creates a symbol table of a symbol table operation.
+    ///
+    /// It returns `None` if the operation is not a symbol table.
+    pub fn new(operation: OperationRef<'c, 'a>) -> Option<Self> {
+        let raw = unsafe { mlirSymbolTableCreate(operation.to_raw()) };
+
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(Self { raw, operation })
+        }
+    }
+
+    /// Returns a name of a symbol attribute.
+    pub fn symbol_attribute_name() -> &'static str {
+        unsafe { StringRef::from_raw(mlirSymbolTableGetSymbolAttributeName()) }
+            .as_str()
+            .unwrap()
+    }
+
+    /// Returns a name of a symbol visibility attribute.
+    pub fn visibility_attribute_name() -> &'static str {
+        unsafe { StringRef::from_raw(mlirSymbolTableGetVisibilityAttributeName()) }
+            .as_str()
+            .unwrap()
+    }
+
+    /// Returns a symbol table operation.
+    pub fn operation(&self) -> OperationRef<'c, 'a> {
+        self.operation
+    }
+
+    /// Looks up a symbol.
+    pub fn lookup(&self, name: &str) -> Option<OperationRef<'c, 'a>> {
+        unsafe {
+            OperationRef::from_option_raw(mlirSymbolTableLookup(
+                self.raw,
+                StringRef::new(name).to_raw(),
+            ))
+        }
+    }
+
+    /// Looks up a symbol and returns a mutable reference to it.
+    pub fn lookup_mut(&self, name: &str) -> Option<OperationRefMut<'c, 'a>> {
+        unsafe {
+            OperationRefMut::from_option_raw(mlirSymbolTableLookup(
+                self.raw,
+                StringRef::new(name).to_raw(),
+            ))
+        }
+    }
+
+    /// Inserts a symbol operation into the body of a symbol table operation.
+    ///
+    /// The symbol is renamed if its name conflicts with an existing one. Its
+    /// final name is available as the symbol attribute of the returned
+    /// operation.
+    pub fn insert(&mut self, operation: Operation<'c>) -> OperationRef<'c, 'a> {
+        unsafe {
+            let operation = operation.into_raw();
+
+            mlirSymbolTableInsert(self.raw, operation);
+
+            OperationRef::from_raw(operation)
+        }
+    }
+
+    /// Removes a symbol operation from a symbol table and erases it.
+    ///
+    /// # Safety
+    ///
+    /// No other references to the operation, such as ones returned by
+    /// [`insert`](Self::insert) or [`lookup`](Self::lookup), must be used
+    /// after the erasure.
+    pub unsafe fn erase(&mut self, operation: OperationRefMut<'c, 'a>) {
+        unsafe { mlirSymbolTableErase(self.raw, operation.to_raw()) }
+    }
+
+    /// Renames a symbol operation and all uses of it in the symbol table
+    /// operation.
+    ///
+    /// It returns an error if another symbol already has the new name.
+    pub fn rename(
+        &mut self,
+        mut operation: OperationRefMut<'c, 'a>,
+        name: &str,
+    ) -> Result<(), Error> {
+        let symbol_name = Self::symbol_attribute_name();
+        let old_name = StringAttribute::try_from(operation.attribute(symbol_name)?)?.value();
+
+        if old_name == name {
+            return Ok(());
+        } else if self.lookup(name).is_some() {
+            return Err(Error::SymbolConflict(name.into()));
+        }
+
+        Self::replace_all_symbol_uses(old_name, name, self.operation)?;
+
+        operation.set_attribute(
+            symbol_name,
+            StringAttribute::new(unsafe { operation.context().to_ref() }, name).into(),
+        );
+
+        // The C API does not expose symbol removal without erasure. So we
+        // rebuild the table to invalidate the cached entry of the old name.
+        unsafe {
+            mlirSymbolTableDestroy(self.raw);
+            self.raw = mlirSymbolTableCreate(self.operation.to_raw());
+        }
+
+        Ok(())
+    }
+
+    /// Returns visibility of a symbol operation.
+    pub fn visibility(operation: &Operation<'c>) -> Result<SymbolVisibility, Error> {
+        match operation.attribute(Self::visibility_attribute_name()) {
+            Ok(attribute) => StringAttribute::try_from(attribute)?.value().parse(),
+            Err(_) => Ok(SymbolVisibility::Public),
+        }
+    }
+
+    /// Sets visibility of a symbol operation.
+    pub fn set_visibility(operation: &mut Operation<'c>, visibility: SymbolVisibility) {
+        let name = Self::visibility_attribute_name();
+
+        match visibility {
+            SymbolVisibility::Public => {
+                // Public visibility is represented by the absence of the attribute.
+                let _ = operation.remove_attribute(name);
+            }
+            SymbolVisibility::Private | SymbolVisibility::Nested => {
+                let attribute = StringAttribute::new(
+                    unsafe { operation.context().to_ref() },
+                    visibility.as_str(),
+                );
+
+                operation.set_attribute(name, attribute.into());
+            }
+        }
+    }
+
+    /// Replaces all uses of a symbol with a new one in all nested operations
+    /// of a given operation.
+    ///
+    /// Uses in nested symbol tables are replaced as well.
+    pub fn replace_all_symbol_uses(
+        old_name: &str,
+        new_name: &str,
+        operation: OperationRef<'c, '_>,
+    ) -> Result<(), Error> {
+        let result = LogicalResult::from_raw(unsafe {
+            mlirSymbolTableReplaceAllSymbolUses(
+                StringRef::new(old_name).to_raw(),
+                StringRef::new(new_name).to_raw(),
+                operation.to_raw(),
+            )
+        });
+
+        if result.is_success() {
+            Ok(())
+        } else {
+            Err(Error::SymbolUseReplacement(old_name.into()))
+        }
+    }
+
+    /// Walks all symbol table operations nested in a given operation,
+    /// including itself.
+    ///
+    /// A callback receives a symbol table operation and a flag indicating if
+    /// all uses of its symbols are visible.
+    pub fn walk_symbol_tables<F: FnMut(OperationRef<'c, '_>, bool)>(
+        operation: OperationRef<'c, '_>,
+        all_uses_visible: bool,
+        mut callback: F,
+    ) {
+        unsafe extern "C" fn handle<'c, F: FnMut(OperationRef<'c, '_>, bool)>(
+            operation: MlirOperation,
+            all_uses_visible: bool,
+            data: *mut c_void,
+        ) {
+            (*(data as *mut F))(OperationRef::from_raw(operation), all_uses_visible)
+        }
+
+        unsafe {
+            mlirSymbolTableWalkSymbolTables(
+                operation.to_raw(),
+                all_uses_visible,
+                Some(handle::<F>),
+                &mut callback as *mut _ as *mut c_void,
+            )
+        }
+    }
+
+    /// Converts a symbol table into a raw object.
+    pub const fn to_raw(&self) -> MlirSymbolTable {
+        self.raw
+    }
+}
+
+impl Drop for SymbolTable<'_, '_> {
+    fn drop(&mut self) {
+        unsafe { mlirSymbolTableDestroy(self.raw) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dialect::func,
+        ir::{
+            attribute::TypeAttribute, block::BlockLike, operation::OperationBuilder,
+            r#type::FunctionType, Block, Location, Module, Region, RegionLike,
+        },
+        test::create_test_context,
+        Context,
+    };
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    fn parse_module(context: &Context) -> Module {
+        Module::parse(
+            context,
+            indoc!(
+                "
+                module {
+                    func.func @foo() {
+                        return
+                    }
+                    func.func @bar() {
+                        call @foo() : () -> ()
+                        return
+                    }
+                }
+                "
+            ),
+        )
+        .unwrap()
+    }
+
+    fn create_function<'c>(context: &'c Context, name: &str) -> Operation<'c> {
+        let location = Location::unknown(context);
+
+        func::func(
+            context,
+            StringAttribute::new(context, name),
+            TypeAttribute::new(FunctionType::new(context, &[], &[]).into()),
+            {
+                let block = Block::new(&[]);
+                block.append_operation(func::r#return(&[], location));
+
+                let region = Region::new();
+                region.append_block(block);
+                region
+            },
+            &[],
+            location,
+        )
+    }
+
+    fn symbol_name(operation: &Operation) -> String {
+        StringAttribute::try_from(
+            operation
+                .attribute(SymbolTable::symbol_attribute_name())
+                .unwrap(),
+        )
+        .unwrap()
+        .value()
+        .into()
+    }
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+        let module = parse_module(&context);
+
+        assert!(SymbolTable::new(module.as_operation()).is_some());
+    }
+
+    #[test]
+    fn new_none() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let block = Block::new(&[]);
+        let operation = block.append_operation(
+            OperationBuilder::new("foo", Location::unknown(&context))
+                .build()
+                .unwrap(),
+        );
+
+        assert!(SymbolTable::new(operation).is_none());
+    }
+
+    #[test]
+    fn attribute_names() {
+        assert_eq!(SymbolTable::symbol_attribute_name(), "sym_name");
+        assert_eq!(SymbolTable::visibility_attribute_name(), "sym_visibility");
+    }
+
+    #[test]
+    fn lookup() {
+        let context = create_test_context();
+        let module = parse_module(&context);
+        let table = SymbolTable::new(module.as_operation()).unwrap();
+
+        assert_eq!(symbol_name(&table.lookup("foo").unwrap()), "foo");
+        assert_eq!(table.lookup("baz"), None);
+    }
+
+    #[test]
+    fn insert() {
+        let context = create_test_context();
+        let module = parse_module(&context);
+        let mut table = SymbolTable::new(module.as_operation()).unwrap();
+
+        let operation = table.insert(create_function(&context, "baz"));
+
+        assert_eq!(symbol_name(&operation), "baz");
+        assert_eq!(table.lookup("baz"), Some(operation));
+        assert!(module.as_operation().verify());
+    }
+
+    #[test]
+    fn insert_conflict() {
+        let context = create_test_context();
+        let module = parse_module(&context);
+        let mut table = SymbolTable::new(module.as_operation()).unwrap();
+
+        let operation = table.insert(create_function(&context, "foo"));
+        let name = symbol_name(&operation);
+
+        assert_ne!(name, "foo");
+        assert!(name.starts_with("foo"));
+        assert_eq!(table.lookup(&name), Some(operation));
+        assert!(module.as_operation().verify());
+    }
+
+    #[test]
+    fn erase() {
+        let context = create_test_context();
+        let module = parse_module(&context);
+        let mut table = SymbolTable::new(module.as_operation()).unwrap();
+
+        table.insert(create_function(&context, "baz"));
+        unsafe { table.erase(table.lookup_mut("baz").unwrap()) };
+
+        assert_eq!(table.lookup("baz"), None);
+        assert!(module.as_operation().verify());
+    }
+
+    #[test]
+    fn rename() {
+        let context = create_test_context();
+        let module = parse_module(&context);
+        let mut table = SymbolTable::new(module.as_operation()).unwrap();
+
+        table
+            .rename(table.lookup_mut("foo").unwrap(), "baz")
+            .unwrap();
+
+        assert_eq!(table.lookup("foo"), None);
+        assert_eq!(symbol_name(&table.lookup("baz").unwrap()), "baz");
+        assert!(module.as_operation().to_string().contains("call @baz()"));
+        assert!(module.as_operation().verify());
+    }
+
+    #[test]
+    fn rename_conflict() {
+        let context = create_test_context();
+        let module = parse_module(&context);
+        let mut table = SymbolTable::new(module.as_operation()).unwrap();
+
+        assert_eq!(
+            table.rename(table.lookup_mut("foo").unwrap(), "bar"),
+            Err(Error::SymbolConflict("bar".into()))
+        );
+        assert_eq!(symbol_name(&table.lookup("foo").unwrap()), "foo");
+        assert!(module.as_operation().to_string().contains("call @foo()"));
+    }
+
+    #[test]
+    fn rename_to_same_name() {
+        let context = create_test_context();
+        let module = parse_module(&context);
+        let mut table = SymbolTable::new(module.as_operation()).unwrap();
+
+        table
+            .rename(table.lookup_mut("foo").unwrap(), "foo")
+            .unwrap();
+
+        assert_eq!(symbol_name(&table.lookup("foo").unwrap()), "foo");
+    }
+
+    #[test]
+    fn replace_all_symbol_uses() {
+        let context = create_test_context();
+        let module = parse_module(&context);
+
+        SymbolTable::replace_all_symbol_uses("foo", "baz", module.as_operation()).unwrap();
+
+        assert!(module.as_operation().to_string().contains("call @baz()"));
+    }
+
+    #[test]
+    fn visibility() {
+        let context = create_test_context();
+        let module = parse_module(&context);
+        let table = SymbolTable::new(module.as_operation()).unwrap();
+        let mut operation = table.lookup_mut("foo").unwrap();
+
+        assert_eq!(
+            SymbolTable::visibility(&operation),
+            Ok(SymbolVisibility::Public)
+        );
+
+        SymbolTable::set_visibility(&mut operation, SymbolVisibility::Private);
+
+        assert_eq!(
+            SymbolTable::visibility(&operation),
+            Ok(SymbolVisibility::Private)
+        );
+
+        SymbolTable::set_visibility(&mut operation, SymbolVisibility::Public);
+
+        assert_eq!(
+            SymbolTable::visibility(&operation),
+            Ok(SymbolVisibility::Public)
+        );
+    }
+
+    #[test]
+    fn walk_symbol_tables() {
+        let context = create_test_context();
+        let module = parse_module(&context);
+        let mut operations = vec![];
+
+        SymbolTable::walk_symbol_tables(module.as_operation(), true, |operation, _| {
+            operations.push(operation.to_raw().ptr);
+        });
+
+        assert_eq!(operations, [module.as_operation().to_raw().ptr]);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::Error;
+use std::str::FromStr;
+
+/// Symbol visibility.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SymbolVisibility {
+    /// A symbol visible from outside of its symbol table.
+    Public,
+    /// A symbol visible only in its symbol table.
+    Private,
+    /// A symbol visible from its parent symbol tables but not from outside of
+    /// the outermost one.
+    Nested,
+}
+
+impl SymbolVisibility {
+    /// Returns a string representation used in a symbol visibility attribute.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Private => "private",
+            Self::Nested => "nested",
+        }
+    }
+}
+
+impl FromStr for SymbolVisibility {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<Self, Error> {
+        Ok(match string {
+            "public" => Self::Public,
+            "private" => Self::Private,
+            "nested" => Self::Nested,
+            _ => return Err(Error::UnknownSymbolVisibility(string.into())),
+        })
+    }
+}
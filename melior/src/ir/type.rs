@@ -2,22 +2,32 @@
 
 #[macro_use]
 mod r#macro;
+mod complex;
+mod float;
 mod function;
 pub mod id;
 mod integer;
 mod mem_ref;
+mod none;
+mod opaque;
 mod ranked_tensor;
 mod shaped_type_like;
 mod tuple;
+mod type_class;
 mod type_like;
+mod unranked_mem_ref;
+mod unranked_tensor;
+mod vector;
 
 pub use self::{
-    function::FunctionType, id::TypeId, integer::IntegerType, mem_ref::MemRefType,
+    complex::ComplexType, float::FloatType, function::FunctionType, id::TypeId,
+    integer::IntegerType, mem_ref::MemRefType, none::NoneType, opaque::OpaqueType,
     ranked_tensor::RankedTensorType, shaped_type_like::ShapedTypeLike, tuple::TupleType,
-    type_like::TypeLike,
+    type_class::TypeClass, type_like::TypeLike, unranked_mem_ref::UnrankedMemRefType,
+    unranked_tensor::UnrankedTensorType, vector::VectorType,
 };
 use super::Location;
-use crate::{context::Context, string_ref::StringRef, utility::print_callback};
+use crate::{context::Context, string_ref::StringRef, utility::print_callback, Error};
 use mlir_sys::{
     mlirBF16TypeGet, mlirF16TypeGet, mlirF32TypeGet, mlirF64TypeGet, mlirIndexTypeGet,
     mlirNoneTypeGet, mlirTypeEqual, mlirTypeParseGet, mlirTypePrint, mlirVectorTypeGet,
@@ -48,6 +58,16 @@ impl<'c> Type<'c> {
         }
     }
 
+    /// Parses a type and returns diagnostics on failure.
+    pub fn parse_with_diagnostics(context: &'c Context, source: &str) -> Result<Self, Error> {
+        let capture = context.capture_diagnostics();
+
+        Self::parse(context, source).ok_or_else(|| Error::Parse {
+            r#type: "type",
+            diagnostics: capture.finish(),
+        })
+    }
+
     /// Creates a bfloat16 type.
     pub fn bfloat16(context: &'c Context) -> Self {
         unsafe { Self::from_raw(mlirBF16TypeGet(context.to_raw())) }
@@ -171,11 +191,18 @@ impl Debug for Type<'_> {
 
 from_subtypes!(
     Type,
+    ComplexType,
+    FloatType,
     FunctionType,
     IntegerType,
     MemRefType,
+    NoneType,
+    OpaqueType,
     RankedTensorType,
-    TupleType
+    TupleType,
+    UnrankedMemRefType,
+    UnrankedTensorType,
+    VectorType,
 );
 
 #[cfg(test)]
@@ -190,6 +217,20 @@ mod tests {
         Type::parse(&context, "f32");
     }
 
+    #[test]
+    fn parse_with_diagnostics() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Type::parse_with_diagnostics(&context, "f32"),
+            Ok(Type::float32(&context))
+        );
+        assert!(matches!(
+            Type::parse_with_diagnostics(&context, "f33"),
+            Err(Error::Parse { r#type: "type", diagnostics }) if diagnostics.len() == 1
+        ));
+    }
+
     #[test]
     fn integer() {
         let context = create_test_context();
@@ -0,0 +1,60 @@
+use super::TypeLike;
+use crate::{ir::Type, Error};
+use mlir_sys::{mlirComplexTypeGet, mlirComplexTypeGetElementType, MlirType};
+
+/// A complex type.
+#[derive(Clone, Copy, Debug)]
+pub struct ComplexType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> ComplexType<'c> {
+    /// Creates a complex type.
+    pub fn new(r#type: Type<'c>) -> Self {
+        unsafe { Self::from_raw(mlirComplexTypeGet(r#type.to_raw())) }
+    }
+
+    /// Returns an element type.
+    pub fn element(&self) -> Type<'c> {
+        unsafe { Type::from_raw(mlirComplexTypeGetElementType(self.r#type.to_raw())) }
+    }
+}
+
+type_traits!(ComplexType, is_complex, "complex");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(ComplexType::new(Type::float32(&context))),
+            Type::parse(&context, "complex<f32>").unwrap()
+        );
+    }
+
+    #[test]
+    fn element() {
+        let context = Context::new();
+
+        assert_eq!(
+            ComplexType::new(Type::float64(&context)).element(),
+            Type::float64(&context)
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let context = Context::new();
+
+        assert!(ComplexType::try_from(Type::parse(&context, "complex<i32>").unwrap()).is_ok());
+        assert_eq!(
+            ComplexType::try_from(Type::index(&context)).unwrap_err(),
+            Error::TypeExpected("complex", "index".into())
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use super::TypeLike;
+use crate::{ir::Type, Context, Error};
+use mlir_sys::{
+    mlirBF16TypeGet, mlirF16TypeGet, mlirF32TypeGet, mlirF64TypeGet, mlirFloat8E4M3B11FNUZTypeGet,
+    mlirFloat8E4M3FNTypeGet, mlirFloat8E4M3FNUZTypeGet, mlirFloat8E4M3TypeGet,
+    mlirFloat8E5M2FNUZTypeGet, mlirFloat8E5M2TypeGet, mlirFloatTypeGetWidth, mlirTF32TypeGet,
+    MlirType,
+};
+
+/// A float type.
+#[derive(Clone, Copy, Debug)]
+pub struct FloatType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> FloatType<'c> {
+    /// Creates a bfloat16 type.
+    pub fn bfloat16(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirBF16TypeGet(context.to_raw())) }
+    }
+
+    /// Creates a float16 type.
+    pub fn float16(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirF16TypeGet(context.to_raw())) }
+    }
+
+    /// Creates a float32 type.
+    pub fn float32(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirF32TypeGet(context.to_raw())) }
+    }
+
+    /// Creates a float64 type.
+    pub fn float64(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirF64TypeGet(context.to_raw())) }
+    }
+
+    /// Creates a TF32 type.
+    pub fn tf32(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirTF32TypeGet(context.to_raw())) }
+    }
+
+    /// Creates a float8 E5M2 type.
+    pub fn float8e5m2(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirFloat8E5M2TypeGet(context.to_raw())) }
+    }
+
+    /// Creates a float8 E4M3 type.
+    pub fn float8e4m3(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirFloat8E4M3TypeGet(context.to_raw())) }
+    }
+
+    /// Creates a float8 E4M3FN type.
+    pub fn float8e4m3fn(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirFloat8E4M3FNTypeGet(context.to_raw())) }
+    }
+
+    /// Creates a float8 E5M2FNUZ type.
+    pub fn float8e5m2fnuz(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirFloat8E5M2FNUZTypeGet(context.to_raw())) }
+    }
+
+    /// Creates a float8 E4M3FNUZ type.
+    pub fn float8e4m3fnuz(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirFloat8E4M3FNUZTypeGet(context.to_raw())) }
+    }
+
+    /// Creates a float8 E4M3B11FNUZ type.
+    pub fn float8e4m3b11fnuz(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirFloat8E4M3B11FNUZTypeGet(context.to_raw())) }
+    }
+
+    /// Returns a bit width.
+    pub fn width(&self) -> u32 {
+        unsafe { mlirFloatTypeGetWidth(self.r#type.to_raw()) }
+    }
+}
+
+type_traits!(FloatType, is_float, "float");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        for (r#type, source, width) in [
+            (FloatType::bfloat16(&context), "bf16", 16),
+            (FloatType::float16(&context), "f16", 16),
+            (FloatType::float32(&context), "f32", 32),
+            (FloatType::float64(&context), "f64", 64),
+            (FloatType::tf32(&context), "tf32", 19),
+            (FloatType::float8e5m2(&context), "f8E5M2", 8),
+            (FloatType::float8e4m3(&context), "f8E4M3", 8),
+            (FloatType::float8e4m3fn(&context), "f8E4M3FN", 8),
+            (FloatType::float8e5m2fnuz(&context), "f8E5M2FNUZ", 8),
+            (FloatType::float8e4m3fnuz(&context), "f8E4M3FNUZ", 8),
+            (FloatType::float8e4m3b11fnuz(&context), "f8E4M3B11FNUZ", 8),
+        ] {
+            assert_eq!(Type::from(r#type), Type::parse(&context, source).unwrap());
+            assert_eq!(r#type.width(), width);
+        }
+    }
+
+    #[test]
+    fn type_checks() {
+        let context = Context::new();
+
+        assert!(Type::from(FloatType::float8e4m3fn(&context)).is_float8e4m3fn());
+        assert!(Type::from(FloatType::float8e5m2(&context)).is_float8e5m2());
+        assert!(!Type::from(FloatType::float8e5m2(&context)).is_float8e4m3fn());
+    }
+
+    #[test]
+    fn try_from() {
+        let context = Context::new();
+
+        assert!(FloatType::try_from(Type::parse(&context, "f8E5M2").unwrap()).is_ok());
+        assert_eq!(
+            FloatType::try_from(Type::index(&context)).unwrap_err(),
+            Error::TypeExpected("float", "index".into())
+        );
+    }
+}
@@ -12,7 +12,7 @@ macro_rules! type_traits {
             type Error = crate::Error;
 
             fn try_from(r#type: crate::ir::r#type::Type<'c>) -> Result<Self, Self::Error> {
-                if r#type.$is_type() {
+                if <Self as crate::ir::r#type::TypeClass>::is_instance(r#type) {
                     Ok(unsafe { Self::from_raw(r#type.to_raw()) })
                 } else {
                     Err(Error::TypeExpected($string, r#type.to_string()))
@@ -20,6 +20,12 @@ macro_rules! type_traits {
             }
         }
 
+        impl<'c> crate::ir::r#type::TypeClass<'c> for $name<'c> {
+            fn is_instance(r#type: crate::ir::r#type::Type<'c>) -> bool {
+                r#type.$is_type()
+            }
+        }
+
         impl<'c> crate::ir::r#type::TypeLike<'c> for $name<'c> {
             fn to_raw(&self) -> mlir_sys::MlirType {
                 self.r#type.to_raw()
@@ -1,11 +1,16 @@
 use super::{shaped_type_like::ShapedTypeLike, TypeLike};
 use crate::{
-    ir::{affine_map::AffineMap, attribute::AttributeLike, Attribute, Location, Type},
+    ir::{
+        affine_map::AffineMap,
+        attribute::{AttributeLike, StridedLayoutAttribute},
+        Attribute, Location, Type,
+    },
     Error,
 };
 use mlir_sys::{
     mlirMemRefTypeGet, mlirMemRefTypeGetAffineMap, mlirMemRefTypeGetChecked,
-    mlirMemRefTypeGetLayout, mlirMemRefTypeGetMemorySpace, MlirType,
+    mlirMemRefTypeGetLayout, mlirMemRefTypeGetMemorySpace, mlirShapedTypeIsDynamicStrideOrOffset,
+    MlirType,
 };
 
 /// A mem-ref type.
@@ -68,6 +73,45 @@ impl<'c> MemRefType<'c> {
         unsafe { Attribute::from_option_raw(mlirMemRefTypeGetMemorySpace(self.r#type.to_raw())) }
     }
 
+    /// Returns strides and an offset.
+    ///
+    /// Dynamic strides and offsets are `None`. It returns `None` if a layout is
+    /// neither an identity layout nor a strided layout, and an error if strides
+    /// of an identity layout overflow.
+    pub fn strides_and_offset(&self) -> Result<Option<(Vec<Option<i64>>, Option<i64>)>, Error> {
+        Ok(
+            if let Ok(layout) = StridedLayoutAttribute::try_from(self.layout()) {
+                Some((
+                    layout.strides().map(static_stride_or_offset).collect(),
+                    static_stride_or_offset(layout.offset()),
+                ))
+            } else if self.affine_map().is_identity() {
+                let shape = self.shape();
+                let mut stride = Some(1i64);
+                let mut strides = vec![stride; shape.len().min(1)];
+
+                for &dimension in shape.iter().skip(1).rev() {
+                    stride = match (stride, dimension) {
+                        (Some(stride), Some(dimension)) => Some(
+                            i64::try_from(dimension)
+                                .ok()
+                                .and_then(|dimension| stride.checked_mul(dimension))
+                                .ok_or_else(|| Error::StrideOverflow(self.to_string()))?,
+                        ),
+                        _ => None,
+                    };
+                    strides.push(stride);
+                }
+
+                strides.reverse();
+
+                Some((strides, Some(0)))
+            } else {
+                None
+            },
+        )
+    }
+
     unsafe fn from_option_raw(raw: MlirType) -> Option<Self> {
         if raw.ptr.is_null() {
             None
@@ -79,6 +123,14 @@ impl<'c> MemRefType<'c> {
 
 impl<'c> ShapedTypeLike<'c> for MemRefType<'c> {}
 
+fn static_stride_or_offset(value: i64) -> Option<i64> {
+    if unsafe { mlirShapedTypeIsDynamicStrideOrOffset(value) } {
+        None
+    } else {
+        Some(value)
+    }
+}
+
 type_traits!(MemRefType, is_mem_ref, "mem ref");
 
 #[cfg(test)]
@@ -133,6 +185,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn strides_and_offset() {
+        let context = Context::new();
+
+        assert_eq!(
+            MemRefType::new(Type::index(&context), &[2, 3, 4], None, None)
+                .strides_and_offset()
+                .unwrap(),
+            Some((vec![Some(12), Some(4), Some(1)], Some(0)))
+        );
+        assert_eq!(
+            MemRefType::new(Type::index(&context), &[2, i64::MIN, 4], None, None)
+                .strides_and_offset()
+                .unwrap(),
+            Some((vec![None, Some(4), Some(1)], Some(0)))
+        );
+        assert_eq!(
+            MemRefType::try_from(
+                Type::parse(&context, "memref<2x3xf32, strided<[6, 1], offset: 2>>").unwrap()
+            )
+            .unwrap()
+            .strides_and_offset()
+            .unwrap(),
+            Some((vec![Some(6), Some(1)], Some(2)))
+        );
+        assert_eq!(
+            MemRefType::try_from(
+                Type::parse(&context, "memref<2x3xf32, strided<[?, 1], offset: ?>>").unwrap()
+            )
+            .unwrap()
+            .strides_and_offset()
+            .unwrap(),
+            Some((vec![None, Some(1)], None))
+        );
+        assert_eq!(
+            MemRefType::try_from(
+                Type::parse(
+                    &context,
+                    "memref<2x3xf32, affine_map<(d0, d1) -> (d1, d0)>>"
+                )
+                .unwrap()
+            )
+            .unwrap()
+            .strides_and_offset()
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn strides_and_offset_overflow() {
+        let context = Context::new();
+
+        assert_eq!(
+            MemRefType::new(Type::index(&context), &[2, 1 << 32, 1 << 32], None, None)
+                .strides_and_offset(),
+            Err(Error::StrideOverflow(
+                "memref<2x4294967296x4294967296xindex>".into()
+            ))
+        );
+    }
+
     #[test]
     fn memory_space() {
         let context = Context::new();
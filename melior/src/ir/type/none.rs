@@ -0,0 +1,38 @@
+use super::TypeLike;
+use crate::{ir::Type, Context, Error};
+use mlir_sys::{mlirNoneTypeGet, MlirType};
+
+/// A none type.
+#[derive(Clone, Copy, Debug)]
+pub struct NoneType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> NoneType<'c> {
+    /// Creates a none type.
+    pub fn new(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirNoneTypeGet(context.to_raw())) }
+    }
+}
+
+type_traits!(NoneType, is_none, "none");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(Type::from(NoneType::new(&context)), Type::none(&context));
+    }
+
+    #[test]
+    fn try_from() {
+        let context = Context::new();
+
+        assert!(NoneType::try_from(Type::none(&context)).is_ok());
+        assert!(NoneType::try_from(Type::index(&context)).is_err());
+    }
+}
@@ -0,0 +1,79 @@
+use super::TypeLike;
+use crate::{ir::Type, Context, Error, StringRef};
+use mlir_sys::{
+    mlirOpaqueTypeGet, mlirOpaqueTypeGetData, mlirOpaqueTypeGetDialectNamespace, MlirType,
+};
+
+/// An opaque type.
+///
+/// It holds type data of a dialect not registered in a context.
+#[derive(Clone, Copy, Debug)]
+pub struct OpaqueType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> OpaqueType<'c> {
+    /// Creates an opaque type.
+    pub fn new(context: &'c Context, namespace: &str, data: &str) -> Self {
+        unsafe {
+            Self::from_raw(mlirOpaqueTypeGet(
+                context.to_raw(),
+                StringRef::new(namespace).to_raw(),
+                StringRef::new(data).to_raw(),
+            ))
+        }
+    }
+
+    /// Returns a dialect namespace.
+    pub fn namespace(&self) -> &'c str {
+        unsafe { StringRef::from_raw(mlirOpaqueTypeGetDialectNamespace(self.r#type.to_raw())) }
+            .as_str()
+            .unwrap()
+    }
+
+    /// Returns data.
+    pub fn data(&self) -> &'c str {
+        unsafe { StringRef::from_raw(mlirOpaqueTypeGetData(self.r#type.to_raw())) }
+            .as_str()
+            .unwrap()
+    }
+}
+
+type_traits!(OpaqueType, is_opaque, "opaque");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(OpaqueType::new(&context, "foo", "bar")).to_string(),
+            "!foo.bar"
+        );
+    }
+
+    #[test]
+    fn namespace() {
+        let context = Context::new();
+
+        assert_eq!(OpaqueType::new(&context, "foo", "bar").namespace(), "foo");
+    }
+
+    #[test]
+    fn data() {
+        let context = Context::new();
+
+        assert_eq!(OpaqueType::new(&context, "foo", "bar").data(), "bar");
+    }
+
+    #[test]
+    fn try_from() {
+        let context = Context::new();
+
+        assert!(OpaqueType::try_from(Type::from(OpaqueType::new(&context, "foo", "bar"))).is_ok());
+        assert!(OpaqueType::try_from(Type::index(&context)).is_err());
+    }
+}
@@ -3,7 +3,7 @@ use crate::Error;
 use super::{Type, TypeLike};
 use mlir_sys::{
     mlirShapedTypeGetDimSize, mlirShapedTypeGetElementType, mlirShapedTypeGetRank,
-    mlirShapedTypeHasRank,
+    mlirShapedTypeHasRank, mlirShapedTypeHasStaticShape, mlirShapedTypeIsDynamicDim,
 };
 
 /// Trait for shaped types.
@@ -35,6 +35,44 @@ pub trait ShapedTypeLike<'c>: TypeLike<'c> {
     fn has_rank(&self) -> bool {
         unsafe { mlirShapedTypeHasRank(self.to_raw()) }
     }
+
+    /// Checks if a dimension is dynamic.
+    fn is_dynamic_dim(&self, index: usize) -> Result<bool, Error> {
+        if index < self.rank() {
+            Ok(unsafe { mlirShapedTypeIsDynamicDim(self.to_raw(), index as isize) })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "dimension",
+                value: unsafe { Type::from_raw(self.to_raw()) }.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns a number of dynamic dimensions.
+    fn num_dynamic_dims(&self) -> usize {
+        (0..self.rank())
+            .filter(|&index| unsafe { mlirShapedTypeIsDynamicDim(self.to_raw(), index as isize) })
+            .count()
+    }
+
+    /// Returns a shape where dynamic dimensions are `None`.
+    fn shape(&self) -> Vec<Option<u64>> {
+        (0..self.rank())
+            .map(|index| unsafe {
+                if mlirShapedTypeIsDynamicDim(self.to_raw(), index as isize) {
+                    None
+                } else {
+                    Some(mlirShapedTypeGetDimSize(self.to_raw(), index as isize) as u64)
+                }
+            })
+            .collect()
+    }
+
+    /// Checks if a type has a static shape.
+    fn has_static_shape(&self) -> bool {
+        unsafe { mlirShapedTypeHasStaticShape(self.to_raw()) }
+    }
 }
 
 #[cfg(test)]
@@ -115,4 +153,60 @@ mod tests {
         assert!(MemRefType::new(element_type, &[0], None, None).has_rank(),);
         assert!(MemRefType::new(element_type, &[0, 0], None, None).has_rank(),);
     }
+
+    #[test]
+    fn is_dynamic_dim() {
+        let context = Context::new();
+        let r#type = MemRefType::new(Type::index(&context), &[42, i64::MIN], None, None);
+
+        assert_eq!(r#type.is_dynamic_dim(0), Ok(false));
+        assert_eq!(r#type.is_dynamic_dim(1), Ok(true));
+        assert_eq!(
+            r#type.is_dynamic_dim(2),
+            Err(Error::PositionOutOfBounds {
+                name: "dimension",
+                value: "memref<42x?xindex>".into(),
+                index: 2
+            })
+        );
+    }
+
+    #[test]
+    fn num_dynamic_dims() {
+        let context = Context::new();
+
+        assert_eq!(
+            MemRefType::new(Type::index(&context), &[42, 42], None, None).num_dynamic_dims(),
+            0
+        );
+        assert_eq!(
+            MemRefType::new(Type::index(&context), &[i64::MIN, 42, i64::MIN], None, None)
+                .num_dynamic_dims(),
+            2
+        );
+    }
+
+    #[test]
+    fn shape() {
+        let context = Context::new();
+
+        assert_eq!(
+            MemRefType::new(Type::index(&context), &[], None, None).shape(),
+            vec![]
+        );
+        assert_eq!(
+            MemRefType::new(Type::index(&context), &[42, i64::MIN, 0], None, None).shape(),
+            vec![Some(42), None, Some(0)]
+        );
+    }
+
+    #[test]
+    fn has_static_shape() {
+        let context = Context::new();
+
+        assert!(MemRefType::new(Type::index(&context), &[42, 0], None, None).has_static_shape());
+        assert!(
+            !MemRefType::new(Type::index(&context), &[42, i64::MIN], None, None).has_static_shape()
+        );
+    }
 }
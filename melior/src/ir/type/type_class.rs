@@ -0,0 +1,15 @@
+use super::Type;
+
+/// A trait for type classes.
+///
+/// It checks if types are instances of a type class without converting them.
+pub trait TypeClass<'c>: TryFrom<Type<'c>> {
+    /// Returns `true` if a type is an instance of a type class.
+    fn is_instance(r#type: Type<'c>) -> bool;
+}
+
+impl<'c> TypeClass<'c> for Type<'c> {
+    fn is_instance(_: Type<'c>) -> bool {
+        true
+    }
+}
@@ -1,5 +1,5 @@
-use super::TypeId;
-use crate::{context::ContextRef, dialect::Dialect};
+use super::{Type, TypeClass, TypeId};
+use crate::{context::ContextRef, dialect::Dialect, Error};
 use mlir_sys::{mlirTypeDump, mlirTypeGetContext, mlirTypeGetDialect, mlirTypeGetTypeID, MlirType};
 
 /// A trait for type-like types.
@@ -22,6 +22,30 @@ pub trait TypeLike<'c> {
         unsafe { Dialect::from_raw(mlirTypeGetDialect(self.to_raw())) }
     }
 
+    /// Returns `true` if a type is an instance of a given type class.
+    fn isa<T: TypeClass<'c>>(&self) -> bool {
+        T::is_instance(unsafe { Type::from_raw(self.to_raw()) })
+    }
+
+    /// Casts a type into a given type class.
+    fn cast<T: TryFrom<Type<'c>>>(&self) -> Result<T, Error>
+    where
+        Error: From<T::Error>,
+    {
+        Ok(T::try_from(unsafe { Type::from_raw(self.to_raw()) })?)
+    }
+
+    /// Casts a type into a given type class if it is an instance of it.
+    fn dyn_cast<T: TypeClass<'c>>(&self) -> Option<T> {
+        let r#type = unsafe { Type::from_raw(self.to_raw()) };
+
+        if T::is_instance(r#type) {
+            T::try_from(r#type).ok()
+        } else {
+            None
+        }
+    }
+
     /// Dumps a type.
     fn dump(&self) {
         unsafe { mlirTypeDump(self.to_raw()) }
@@ -84,7 +108,7 @@ mod tests {
     use super::*;
     use crate::{
         ir::{
-            r#type::{FunctionType, IntegerType},
+            r#type::{FunctionType, IntegerType, MemRefType},
             Type,
         },
         Context,
@@ -112,6 +136,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn isa() {
+        let context = Context::new();
+        let r#type = Type::from(IntegerType::new(&context, 64));
+
+        assert!(r#type.isa::<Type>());
+        assert!(r#type.isa::<IntegerType>());
+        assert!(!r#type.isa::<FunctionType>());
+    }
+
+    #[test]
+    fn cast() {
+        let context = Context::new();
+        let r#type = Type::from(IntegerType::new(&context, 64));
+
+        assert_eq!(r#type.cast::<IntegerType>().unwrap().width(), 64);
+        assert_eq!(r#type.cast::<Type>(), Ok(r#type));
+        assert_eq!(
+            r#type.cast::<MemRefType>().unwrap_err(),
+            Error::TypeExpected("mem ref", "i64".into())
+        );
+    }
+
+    #[test]
+    fn dyn_cast() {
+        let context = Context::new();
+        let r#type = Type::from(IntegerType::new(&context, 64));
+
+        assert_eq!(
+            r#type
+                .dyn_cast::<IntegerType>()
+                .map(|r#type| r#type.width()),
+            Some(64)
+        );
+        assert!(r#type.dyn_cast::<FunctionType>().is_none());
+    }
+
     #[test]
     fn is_integer() {
         let context = Context::new();
@@ -0,0 +1,133 @@
+use super::TypeLike;
+use crate::{
+    ir::{attribute::AttributeLike, Attribute, Location, Type},
+    Error,
+};
+use mlir_sys::{
+    mlirShapedTypeGetElementType, mlirUnrankedMemRefTypeGet, mlirUnrankedMemRefTypeGetChecked,
+    mlirUnrankedMemrefGetMemorySpace, MlirType,
+};
+
+/// An unranked mem-ref type.
+#[derive(Clone, Copy, Debug)]
+pub struct UnrankedMemRefType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> UnrankedMemRefType<'c> {
+    /// Creates an unranked mem-ref type.
+    pub fn new(r#type: Type<'c>, memory_space: Option<Attribute<'c>>) -> Self {
+        unsafe {
+            Self::from_raw(mlirUnrankedMemRefTypeGet(
+                r#type.to_raw(),
+                memory_space.unwrap_or_else(|| Attribute::null()).to_raw(),
+            ))
+        }
+    }
+
+    /// Creates an unranked mem-ref type with diagnostics.
+    pub fn checked(
+        location: Location<'c>,
+        r#type: Type<'c>,
+        memory_space: Attribute<'c>,
+    ) -> Option<Self> {
+        unsafe {
+            Self::from_option_raw(mlirUnrankedMemRefTypeGetChecked(
+                location.to_raw(),
+                r#type.to_raw(),
+                memory_space.to_raw(),
+            ))
+        }
+    }
+
+    /// Returns an element type.
+    pub fn element(&self) -> Type<'c> {
+        unsafe { Type::from_raw(mlirShapedTypeGetElementType(self.r#type.to_raw())) }
+    }
+
+    /// Returns a memory space.
+    pub fn memory_space(&self) -> Option<Attribute<'c>> {
+        unsafe {
+            Attribute::from_option_raw(mlirUnrankedMemrefGetMemorySpace(self.r#type.to_raw()))
+        }
+    }
+
+    unsafe fn from_option_raw(raw: MlirType) -> Option<Self> {
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(Self::from_raw(raw))
+        }
+    }
+}
+
+type_traits!(UnrankedMemRefType, is_unranked_mem_ref, "unranked mem ref");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(UnrankedMemRefType::new(Type::float64(&context), None)),
+            Type::parse(&context, "memref<*xf64>").unwrap()
+        );
+    }
+
+    #[test]
+    fn checked() {
+        let context = Context::new();
+        let location = Location::unknown(&context);
+        let memory_space = Attribute::parse(&context, "1 : i64").unwrap();
+
+        assert_eq!(
+            UnrankedMemRefType::checked(location, Type::index(&context), memory_space)
+                .map(Type::from),
+            Type::parse(&context, "memref<*xindex, 1>")
+        );
+        assert!(
+            UnrankedMemRefType::checked(location, Type::none(&context), memory_space).is_none()
+        );
+    }
+
+    #[test]
+    fn element() {
+        let context = Context::new();
+
+        assert_eq!(
+            UnrankedMemRefType::new(Type::index(&context), None).element(),
+            Type::index(&context)
+        );
+    }
+
+    #[test]
+    fn memory_space() {
+        let context = Context::new();
+        let memory_space = Attribute::parse(&context, "1 : i64").unwrap();
+
+        assert_eq!(
+            UnrankedMemRefType::new(Type::index(&context), None).memory_space(),
+            None
+        );
+        assert_eq!(
+            UnrankedMemRefType::new(Type::index(&context), Some(memory_space)).memory_space(),
+            Some(memory_space)
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let context = Context::new();
+
+        assert!(
+            UnrankedMemRefType::try_from(Type::parse(&context, "memref<*xf32>").unwrap()).is_ok()
+        );
+        assert!(
+            UnrankedMemRefType::try_from(Type::parse(&context, "memref<2xf32>").unwrap()).is_err()
+        );
+    }
+}
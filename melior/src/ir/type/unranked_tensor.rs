@@ -0,0 +1,97 @@
+use super::TypeLike;
+use crate::{
+    ir::{Location, Type},
+    Error,
+};
+use mlir_sys::{
+    mlirShapedTypeGetElementType, mlirUnrankedTensorTypeGet, mlirUnrankedTensorTypeGetChecked,
+    MlirType,
+};
+
+/// An unranked tensor type.
+#[derive(Clone, Copy, Debug)]
+pub struct UnrankedTensorType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> UnrankedTensorType<'c> {
+    /// Creates an unranked tensor type.
+    pub fn new(r#type: Type<'c>) -> Self {
+        unsafe { Self::from_raw(mlirUnrankedTensorTypeGet(r#type.to_raw())) }
+    }
+
+    /// Creates an unranked tensor type with diagnostics.
+    pub fn checked(location: Location<'c>, r#type: Type<'c>) -> Option<Self> {
+        unsafe {
+            Self::from_option_raw(mlirUnrankedTensorTypeGetChecked(
+                location.to_raw(),
+                r#type.to_raw(),
+            ))
+        }
+    }
+
+    /// Returns an element type.
+    pub fn element(&self) -> Type<'c> {
+        unsafe { Type::from_raw(mlirShapedTypeGetElementType(self.r#type.to_raw())) }
+    }
+
+    unsafe fn from_option_raw(raw: MlirType) -> Option<Self> {
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(Self::from_raw(raw))
+        }
+    }
+}
+
+type_traits!(UnrankedTensorType, is_unranked_tensor, "unranked tensor");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(UnrankedTensorType::new(Type::float64(&context))),
+            Type::parse(&context, "tensor<*xf64>").unwrap()
+        );
+    }
+
+    #[test]
+    fn checked() {
+        let context = Context::new();
+        let location = Location::unknown(&context);
+
+        assert_eq!(
+            UnrankedTensorType::checked(location, Type::index(&context)).map(Type::from),
+            Type::parse(&context, "tensor<*xindex>")
+        );
+        assert!(UnrankedTensorType::checked(location, Type::none(&context)).is_none());
+    }
+
+    #[test]
+    fn element() {
+        let context = Context::new();
+
+        assert_eq!(
+            UnrankedTensorType::new(Type::index(&context)).element(),
+            Type::index(&context)
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let context = Context::new();
+
+        assert!(
+            UnrankedTensorType::try_from(Type::parse(&context, "tensor<*xf32>").unwrap()).is_ok()
+        );
+        assert!(
+            UnrankedTensorType::try_from(Type::parse(&context, "tensor<2xf32>").unwrap()).is_err()
+        );
+    }
+}
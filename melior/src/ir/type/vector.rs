@@ -0,0 +1,206 @@
+use super::{shaped_type_like::ShapedTypeLike, TypeLike};
+use crate::{
+    ir::{Location, Type},
+    Error,
+};
+use mlir_sys::{
+    mlirVectorTypeGet, mlirVectorTypeGetChecked, mlirVectorTypeGetScalable,
+    mlirVectorTypeGetScalableChecked, mlirVectorTypeIsDimScalable, mlirVectorTypeIsScalable,
+    MlirType,
+};
+
+/// A vector type.
+#[derive(Clone, Copy, Debug)]
+pub struct VectorType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> VectorType<'c> {
+    /// Creates a vector type.
+    pub fn new(dimensions: &[u64], r#type: Type<'c>) -> Self {
+        unsafe {
+            Self::from_raw(mlirVectorTypeGet(
+                dimensions.len() as isize,
+                dimensions.as_ptr() as *const i64,
+                r#type.to_raw(),
+            ))
+        }
+    }
+
+    /// Creates a vector type with diagnostics.
+    pub fn checked(location: Location<'c>, dimensions: &[u64], r#type: Type<'c>) -> Option<Self> {
+        unsafe {
+            Self::from_option_raw(mlirVectorTypeGetChecked(
+                location.to_raw(),
+                dimensions.len() as isize,
+                dimensions.as_ptr() as *const i64,
+                r#type.to_raw(),
+            ))
+        }
+    }
+
+    /// Creates a vector type with dimensions and their scalability flags.
+    pub fn scalable(dimensions: &[(u64, bool)], r#type: Type<'c>) -> Self {
+        let (sizes, scalable) = split_dimensions(dimensions);
+
+        unsafe {
+            Self::from_raw(mlirVectorTypeGetScalable(
+                sizes.len() as isize,
+                sizes.as_ptr(),
+                scalable.as_ptr(),
+                r#type.to_raw(),
+            ))
+        }
+    }
+
+    /// Creates a vector type with dimensions and their scalability flags with
+    /// diagnostics.
+    pub fn scalable_checked(
+        location: Location<'c>,
+        dimensions: &[(u64, bool)],
+        r#type: Type<'c>,
+    ) -> Option<Self> {
+        let (sizes, scalable) = split_dimensions(dimensions);
+
+        unsafe {
+            Self::from_option_raw(mlirVectorTypeGetScalableChecked(
+                location.to_raw(),
+                sizes.len() as isize,
+                sizes.as_ptr(),
+                scalable.as_ptr(),
+                r#type.to_raw(),
+            ))
+        }
+    }
+
+    /// Returns `true` if any dimension is scalable.
+    pub fn is_scalable(&self) -> bool {
+        unsafe { mlirVectorTypeIsScalable(self.r#type.to_raw()) }
+    }
+
+    /// Returns `true` if a dimension is scalable.
+    pub fn is_dim_scalable(&self, index: usize) -> Result<bool, Error> {
+        if index < self.rank() {
+            Ok(unsafe { mlirVectorTypeIsDimScalable(self.r#type.to_raw(), index as isize) })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "dimension",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns scalability flags of dimensions.
+    pub fn scalable_dims(&self) -> Vec<bool> {
+        (0..self.rank())
+            .map(|index| unsafe {
+                mlirVectorTypeIsDimScalable(self.r#type.to_raw(), index as isize)
+            })
+            .collect()
+    }
+
+    unsafe fn from_option_raw(raw: MlirType) -> Option<Self> {
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(Self::from_raw(raw))
+        }
+    }
+}
+
+impl<'c> ShapedTypeLike<'c> for VectorType<'c> {}
+
+fn split_dimensions(dimensions: &[(u64, bool)]) -> (Vec<i64>, Vec<bool>) {
+    dimensions
+        .iter()
+        .map(|&(size, scalable)| (size as i64, scalable))
+        .unzip()
+}
+
+type_traits!(VectorType, is_vector, "vector");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(VectorType::new(&[2, 4], Type::float32(&context))),
+            Type::parse(&context, "vector<2x4xf32>").unwrap()
+        );
+    }
+
+    #[test]
+    fn checked() {
+        let context = Context::new();
+        let location = Location::unknown(&context);
+
+        assert_eq!(
+            VectorType::checked(location, &[4], Type::index(&context)).map(Type::from),
+            Type::parse(&context, "vector<4xindex>")
+        );
+        assert!(VectorType::checked(location, &[0], Type::index(&context)).is_none());
+    }
+
+    #[test]
+    fn shape() {
+        let context = Context::new();
+        let r#type = VectorType::new(&[2, 4], Type::float32(&context));
+
+        assert_eq!(r#type.element(), Type::float32(&context));
+        assert_eq!(r#type.rank(), 2);
+        assert_eq!(r#type.dim_size(1), Ok(4));
+    }
+
+    #[test]
+    fn scalable() {
+        let context = Context::new();
+        let r#type = VectorType::scalable(&[(2, false), (4, true)], Type::float32(&context));
+
+        assert_eq!(
+            Type::from(r#type),
+            Type::parse(&context, "vector<2x[4]xf32>").unwrap()
+        );
+        assert!(r#type.is_scalable());
+        assert_eq!(r#type.is_dim_scalable(0), Ok(false));
+        assert_eq!(r#type.is_dim_scalable(1), Ok(true));
+        assert_eq!(
+            r#type.is_dim_scalable(2),
+            Err(Error::PositionOutOfBounds {
+                name: "dimension",
+                value: "vector<2x[4]xf32>".into(),
+                index: 2
+            })
+        );
+        assert_eq!(r#type.scalable_dims(), vec![false, true]);
+        assert!(!VectorType::new(&[4], Type::float32(&context)).is_scalable());
+    }
+
+    #[test]
+    fn scalable_checked() {
+        let context = Context::new();
+        let location = Location::unknown(&context);
+
+        assert_eq!(
+            VectorType::scalable_checked(location, &[(4, true)], Type::index(&context))
+                .map(Type::from),
+            Type::parse(&context, "vector<[4]xindex>")
+        );
+        assert!(
+            VectorType::scalable_checked(location, &[(0, true)], Type::index(&context)).is_none()
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let context = Context::new();
+
+        assert!(VectorType::try_from(Type::vector(&[4], Type::index(&context))).is_ok());
+        assert!(VectorType::try_from(Type::index(&context)).is_err());
+    }
+}
@@ -80,11 +80,91 @@ mod tests {
     use super::*;
     use crate::{
         ir::{
-            block::BlockLike, operation::OperationBuilder, Attribute, Block, Identifier, Location,
+            block::BlockLike,
+            operation::{AsmState, OperationBuilder, OperationPrintingFlags},
+            Attribute, Block, Identifier, Location, Module, RegionLike,
         },
         test::create_test_context,
         Context,
     };
+    use indoc::indoc;
+
+    #[test]
+    fn print_as_operand() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo(%arg0 : i32) -> i32 {
+                  %0 = arith.addi %arg0, %arg0 : i32
+                  %1 = arith.addi %0, %0 : i32
+                  return %1 : i32
+                }
+                "
+            ),
+        )
+        .unwrap();
+        let operation = module.as_operation();
+        let state = AsmState::for_operation(&operation, OperationPrintingFlags::new());
+        let block = module
+            .body()
+            .first_operation()
+            .unwrap()
+            .region(0)
+            .unwrap()
+            .first_block()
+            .unwrap();
+        let operation = block.first_operation().unwrap();
+        let mut string = String::new();
+
+        block
+            .argument(0)
+            .unwrap()
+            .print_as_operand(&mut string, &state)
+            .unwrap();
+        string.push(' ');
+        operation
+            .next_in_block()
+            .unwrap()
+            .result(0)
+            .unwrap()
+            .print_as_operand(&mut string, &state)
+            .unwrap();
+
+        assert_eq!(string, "%arg0 %1");
+    }
+
+    #[test]
+    fn print_with_flags() {
+        let context = create_test_context();
+        let block = Block::new(&[(Type::index(&context), Location::unknown(&context))]);
+        let operation = block.append_operation(
+            OperationBuilder::new("arith.addi", Location::unknown(&context))
+                .add_operands(&[block.argument(0).unwrap().into(); 2])
+                .enable_result_type_inference()
+                .build()
+                .unwrap(),
+        );
+        let mut string = String::new();
+
+        operation
+            .result(0)
+            .unwrap()
+            .print_with_flags(&mut string, OperationPrintingFlags::new())
+            .unwrap();
+
+        assert_eq!(string, operation.to_string());
+
+        string.clear();
+        block
+            .argument(0)
+            .unwrap()
+            .print_with_flags(&mut string, OperationPrintingFlags::new())
+            .unwrap();
+
+        assert_eq!(string, block.argument(0).unwrap().to_string());
+    }
 
     #[test]
     fn r#type() {
@@ -257,4 +337,101 @@ mod tests {
             "Value(\n%c0 = arith.constant 0 : index\n)"
         );
     }
+
+    #[test]
+    fn uses() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location)]);
+        let argument: Value = block.argument(0).unwrap().into();
+
+        let first = block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[argument])
+                .build()
+                .unwrap(),
+        );
+        let second = block.append_operation(
+            OperationBuilder::new("bar", location)
+                .add_operands(&[argument, argument])
+                .build()
+                .unwrap(),
+        );
+
+        let mut uses = argument
+            .uses()
+            .map(|operand| (operand.owner(), operand.operand_number()))
+            .collect::<Vec<_>>();
+        uses.sort_by_key(|(owner, index)| (*owner != first, *index));
+
+        assert_eq!(uses, [(first, 0), (second, 0), (second, 1)]);
+    }
+
+    #[test]
+    fn uses_none() {
+        let context = create_test_context();
+        let block = Block::new(&[(Type::index(&context), Location::unknown(&context))]);
+
+        assert_eq!(block.argument(0).unwrap().uses().count(), 0);
+    }
+
+    #[test]
+    fn replace_all_uses_with() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location), (r#type, location)]);
+        let first_argument: Value = block.argument(0).unwrap().into();
+        let second_argument: Value = block.argument(1).unwrap().into();
+
+        let operation = block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[first_argument, first_argument])
+                .build()
+                .unwrap(),
+        );
+
+        first_argument.replace_all_uses_with(second_argument);
+
+        assert_eq!(first_argument.uses().count(), 0);
+        assert_eq!(
+            operation.operands().collect::<Vec<_>>(),
+            [second_argument, second_argument]
+        );
+    }
+
+    #[test]
+    fn replace_all_uses_except() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location), (r#type, location)]);
+        let first_argument: Value = block.argument(0).unwrap().into();
+        let second_argument: Value = block.argument(1).unwrap().into();
+
+        let first = block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[first_argument])
+                .build()
+                .unwrap(),
+        );
+        let second = block.append_operation(
+            OperationBuilder::new("bar", location)
+                .add_operands(&[first_argument])
+                .build()
+                .unwrap(),
+        );
+
+        first_argument.replace_all_uses_except(second_argument, &[first]);
+
+        assert_eq!(first.operand(0), Ok(first_argument));
+        assert_eq!(second.operand(0), Ok(second_argument));
+    }
 }
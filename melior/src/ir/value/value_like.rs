@@ -1,7 +1,17 @@
-use super::Type;
+use super::{Type, Value};
+use crate::{
+    ir::{
+        operation::{AsmState, OperationOperand, OperationPrintingFlags, OperationResult},
+        OperationRef,
+    },
+    utility::print_writer_callback,
+};
 use mlir_sys::{
-    mlirValueDump, mlirValueGetType, mlirValueIsABlockArgument, mlirValueIsAOpResult, MlirValue,
+    mlirOperationSetOperand, mlirValueDump, mlirValueGetFirstUse, mlirValueGetType,
+    mlirValueIsABlockArgument, mlirValueIsAOpResult, mlirValuePrintAsOperand,
+    mlirValueReplaceAllUsesOfWith, MlirValue,
 };
+use std::{fmt, iter::successors};
 
 /// A trait for value-like types.
 pub trait ValueLike<'c> {
@@ -23,6 +33,76 @@ pub trait ValueLike<'c> {
         unsafe { mlirValueIsAOpResult(self.to_raw()) }
     }
 
+    /// Returns uses of a value.
+    fn uses(&self) -> impl Iterator<Item = OperationOperand<'c, '_>> {
+        successors(
+            unsafe { OperationOperand::from_option_raw(mlirValueGetFirstUse(self.to_raw())) },
+            OperationOperand::next_use,
+        )
+    }
+
+    /// Replaces all uses of a value with another value.
+    fn replace_all_uses_with(&self, value: Value<'c, '_>) {
+        unsafe { mlirValueReplaceAllUsesOfWith(self.to_raw(), value.to_raw()) }
+    }
+
+    /// Replaces all uses of a value with another value except the ones in given
+    /// operations.
+    fn replace_all_uses_except(&self, value: Value<'c, '_>, operations: &[OperationRef<'c, '_>]) {
+        // Collect uses in advance as replacement modifies the use list.
+        for operand in self.uses().collect::<Vec<_>>() {
+            let owner = operand.owner();
+
+            if !operations.contains(&owner) {
+                unsafe {
+                    mlirOperationSetOperand(
+                        owner.to_raw(),
+                        operand.operand_number() as isize,
+                        value.to_raw(),
+                    )
+                }
+            }
+        }
+    }
+
+    /// Prints a value as an operand, such as `%0`, into a writer.
+    fn print_as_operand<W: fmt::Write>(
+        &self,
+        writer: &mut W,
+        state: &AsmState<'c, '_>,
+    ) -> fmt::Result {
+        let mut data = (writer, Ok(()));
+
+        unsafe {
+            mlirValuePrintAsOperand(
+                self.to_raw(),
+                state.to_raw(),
+                Some(print_writer_callback::<W>),
+                &mut data as *mut _ as *mut _,
+            );
+        }
+
+        data.1
+    }
+
+    /// Prints a value with flags into a writer.
+    ///
+    /// An operation result is printed as its owner operation while a block
+    /// argument is printed in the same way as [`Display`](std::fmt::Display).
+    fn print_with_flags(
+        &self,
+        writer: &mut impl fmt::Write,
+        flags: OperationPrintingFlags,
+    ) -> fmt::Result {
+        if self.is_operation_result() {
+            unsafe { OperationResult::from_raw(self.to_raw()) }
+                .owner()
+                .print_with_flags(writer, flags)
+        } else {
+            write!(writer, "{}", unsafe { Value::from_raw(self.to_raw()) })
+        }
+    }
+
     /// Dumps a value.
     fn dump(&self) {
         unsafe { mlirValueDump(self.to_raw()) }
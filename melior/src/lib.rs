@@ -21,7 +21,7 @@ mod test;
 pub mod utility;
 
 pub use self::{
-    context::{Context, ContextRef},
+    context::{Context, ContextOptions, ContextRef, ThreadPool},
     error::Error,
     execution_engine::ExecutionEngine,
     string_ref::StringRef,
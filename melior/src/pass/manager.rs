@@ -47,6 +47,10 @@ impl PassManager<'_> {
     }
 
     /// Enables IR printing.
+    ///
+    /// IR is printed before and after every pass with the default options of
+    /// MLIR. The C API takes no options to choose passes, the scope, or
+    /// operation printing flags.
     pub fn enable_ir_printing(&self) {
         unsafe { mlirPassManagerEnableIRPrinting(self.raw) }
     }
@@ -64,6 +68,16 @@ impl PassManager<'_> {
         }
     }
 
+    /// Runs passes added to a pass manager against a module and returns
+    /// diagnostics on failure.
+    pub fn run_with_diagnostics(&self, module: &mut Module) -> Result<(), Error> {
+        let context = module.context();
+        let capture = unsafe { context.to_ref() }.capture_diagnostics();
+
+        self.run(module)
+            .map_err(|_| Error::PassFailure(capture.finish()))
+    }
+
     /// Converts a pass manager to an operation pass manager.
     pub fn as_operation_pass_manager(&self) -> OperationPassManager {
         unsafe { OperationPassManager::from_raw(mlirPassManagerGetAsOpPassManager(self.raw)) }
@@ -103,7 +117,7 @@ impl Drop for PassManager<'_> {
 mod tests {
     use super::*;
     use crate::{
-        ir::{Location, Module},
+        ir::{operation::OperationBuilder, BlockLike, Location, Module},
         pass::{self, transform::register_print_op_stats},
         test::create_test_context,
         utility::parse_pass_pipeline,
@@ -151,6 +165,40 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn run_with_diagnostics() {
+        let context = create_test_context();
+        let manager = PassManager::new(&context);
+
+        manager.add_pass(pass::transform::create_canonicalizer());
+
+        assert_eq!(
+            manager.run_with_diagnostics(&mut Module::new(Location::unknown(&context))),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn run_with_diagnostics_error() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let mut module = Module::new(location);
+
+        module.body().append_operation(
+            OperationBuilder::new("func.return", location)
+                .build()
+                .unwrap(),
+        );
+
+        let manager = PassManager::new(&context);
+        manager.add_pass(pass::transform::create_canonicalizer());
+
+        assert!(matches!(
+            manager.run_with_diagnostics(&mut module),
+            Err(Error::PassFailure(diagnostics)) if !diagnostics.is_empty()
+        ));
+    }
+
     #[test]
     fn run_on_function() {
         let context = create_test_context();
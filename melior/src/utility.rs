@@ -11,6 +11,8 @@ use mlir_sys::{
 use std::{
     ffi::c_void,
     fmt::{self, Formatter},
+    io::{self, Write},
+    slice,
     sync::Once,
 };
 
@@ -102,6 +104,38 @@ pub(crate) unsafe extern "C" fn print_string_callback(string: MlirStringRef, dat
     })();
 }
 
+pub(crate) unsafe extern "C" fn print_writer_callback<W: fmt::Write>(
+    string: MlirStringRef,
+    data: *mut c_void,
+) {
+    let (writer, result) = &mut *(data as *mut (&mut W, fmt::Result));
+
+    if result.is_err() {
+        return;
+    }
+
+    *result = (|| {
+        writer.write_str(
+            StringRef::from_raw(string)
+                .as_str()
+                .map_err(|_| fmt::Error)?,
+        )
+    })();
+}
+
+pub(crate) unsafe extern "C" fn write_callback<W: Write>(string: MlirStringRef, data: *mut c_void) {
+    let (writer, result) = &mut *(data as *mut (&mut W, io::Result<()>));
+
+    if result.is_err() {
+        return;
+    }
+
+    *result = writer.write_all(slice::from_raw_parts(
+        string.data as *const u8,
+        string.length,
+    ));
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ir::Location;